@@ -1,7 +1,7 @@
 ///! solana-gadgets common resuable modules
 // Includes
 use std::io::prelude::*;
-use std::{fs::File, io};
+use std::{fs::File, io, thread, time::Duration};
 use yaml_rust::yaml::Yaml;
 use yaml_rust::YamlLoader;
 
@@ -15,6 +15,53 @@ pub fn load_yaml_file(yaml_file: &str) -> Result<Vec<Yaml>, io::Error> {
     Ok(docs)
 }
 
+/// Retry policy for fallible calls (e.g. RPC), waiting 'base_delay'
+/// after the first failure and doubling it after each one that follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+        }
+    }
+
+    /// Delay to wait after failed 'attempt' (starting at 1)
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+
+    /// Calls 'op' with the attempt number until it succeeds or 'max_attempts'
+    /// (at least one) are made, returning the last error
+    pub fn retry<T, E>(&self, mut op: impl FnMut(u32) -> Result<T, E>) -> Result<T, E> {
+        let mut attempt = 1;
+        loop {
+            match op(attempt) {
+                Err(_) if attempt < self.max_attempts => {
+                    thread::sleep(self.delay_for(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -63,4 +110,30 @@ mod tests {
         let y = load_yaml_file(NONEXIST);
         assert!(y.is_err());
     }
+
+    #[test]
+    fn retry_policy_pass() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(10));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(10));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(40));
+        let result = RetryPolicy::new(3, Duration::ZERO).retry(|attempt| {
+            if attempt < 2 {
+                Err(attempt)
+            } else {
+                Ok(attempt)
+            }
+        });
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn retry_policy_exhausted_fail() {
+        let mut calls = 0;
+        let result: Result<(), u32> = RetryPolicy::new(3, Duration::ZERO).retry(|attempt| {
+            calls += 1;
+            Err(attempt)
+        });
+        assert_eq!(result, Err(3));
+        assert_eq!(calls, 3);
+    }
 }
@@ -16,15 +16,40 @@
 //!     2.2 A list of feature IDs to sample for the cluster status
 //!
 
+use futures::{stream, FutureExt, StreamExt};
+use gadgets_common::RetryPolicy;
 use lazy_static::*;
+use rand::Rng;
 use scfs_errors::{ScfsError, ScfsResult};
+use scfs_transport::ScfsTransport;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
+use solana_client::client_error::{ClientErrorKind, Result as ClientResult};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
-    account::Account, clock::Slot, feature, feature_set::FEATURE_NAMES, pubkey::Pubkey,
+    account::Account,
+    clock::{Epoch, Slot, UnixTimestamp},
+    commitment_config::CommitmentConfig,
+    epoch_schedule::EpochSchedule,
+    feature::{self, Feature},
+    feature_set::FEATURE_NAMES,
+    pubkey::Pubkey,
 };
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::Write,
+    fs,
+    future::Future,
+    io::BufRead,
+    path::Path,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use yaml_rust::{Yaml, YamlLoader};
 
 pub mod scfs_errors;
+pub mod scfs_transport;
 
 lazy_static! {
     /// Easy cluster aliases and misc string constants
@@ -34,6 +59,8 @@ lazy_static! {
     pub static ref SCFS_TESTNET: String = "testnet".to_string();
     pub static ref SCFS_MAINNET: String = "mainnet".to_string();
     pub static ref SCFS_DESCRIPTION: String = "description".to_string();
+    /// Description of features not in FEATURE_NAMES or custom_features
+    pub static ref SCFS_UNKNOWN_FEATURE: String = "<unknown feature>".to_string();
 
     /// Easy url lookup map (name -> url)
     /// subject to change! Alternative would be to
@@ -67,6 +94,14 @@ lazy_static! {
         clusters.push(SCFS_MAINNET.clone());
         clusters
     };
+    /// List of public cluster aliases, excludes local
+    pub static ref SCFS_PUBLIC_CLUSTER_LIST: Vec<String> = {
+        SCFS_CLUSTER_LIST
+            .iter()
+            .filter(|c| *c != &*SCFS_LOCAL)
+            .cloned()
+            .collect()
+    };
 
     /// Header Default List
     pub static ref SCFS_HEADER_LIST: Vec<String> = {
@@ -75,23 +110,357 @@ lazy_static! {
         headers.push(SCFS_DESCRIPTION.clone());
         headers
     };
-    /// Features public keys
+    /// Feature names ordered by feature public key
+    pub static ref SCFS_FEATURE_NAMES: BTreeMap<Pubkey, String> = {
+        FEATURE_NAMES
+            .iter()
+            .map(|(pk, name)| (*pk, name.to_string()))
+            .collect()
+    };
+    /// Names shared by more than one feature of SCFS_FEATURE_NAMES
+    pub static ref SCFS_AMBIGUOUS_FEATURE_NAMES: BTreeSet<String> = {
+        split_duplicates(&SCFS_FEATURE_NAMES.values().cloned().collect::<Vec<String>>())
+            .1
+            .into_iter()
+            .collect()
+    };
+    /// Features public keys by name, the inverse of SCFS_FEATURE_NAMES
+    /// without the SCFS_AMBIGUOUS_FEATURE_NAMES
+    pub static ref SCFS_FEATURE_KEYS: HashMap<String, Pubkey> = {
+        SCFS_FEATURE_NAMES
+            .iter()
+            .filter(|(_, name)| !SCFS_AMBIGUOUS_FEATURE_NAMES.contains(*name))
+            .map(|(pk, name)| (name.clone(), *pk))
+            .collect()
+    };
+    /// Features public keys, in SCFS_FEATURE_NAMES order so default rows are stable across runs
     pub static ref SCFS_FEATURE_PKS: Vec<Pubkey> = {
-        FEATURE_NAMES.keys().cloned().collect::<Vec<Pubkey>>()
+        SCFS_FEATURE_NAMES.keys().cloned().collect::<Vec<Pubkey>>()
     };
 }
 
+/// The url for a cluster, the `SCFS_<CLUSTER>_URL` environment
+/// variable (e.g. SCFS_DEVNET_URL) overrides SCFS_URL_LOOKUPS
+pub fn cluster_url(cluster: &str) -> Option<String> {
+    cluster_url_with(cluster, |name| std::env::var(name).ok())
+}
+
+/// The url for a cluster as cluster_url, reading the
+/// `SCFS_<CLUSTER>_URL` variable through 'var'
+fn cluster_url_with(cluster: &str, var: impl Fn(&str) -> Option<String>) -> Option<String> {
+    var(&format!("SCFS_{}_URL", cluster.to_uppercase()))
+        .or_else(|| SCFS_URL_LOOKUPS.get(cluster).cloned())
+}
+
 #[derive(Clone, Debug, PartialEq)]
 /// Criteria for processing feature set statusing
 pub struct ScfsCriteria {
     pub features: Option<Vec<Pubkey>>, // Limits the feature to query status on, defaults to all
     pub clusters: Option<Vec<String>>, // Limits what clusters to query the features on, defaults to all
+    pub keep_decoded: bool, // Retains the decoded feature account per cluster, defaults to false
+    pub custom_features: HashMap<Pubkey, String>, // Extra feature names merged with FEATURE_NAMES, defaults to empty
+    pub batch_size: usize, // Feature accounts fetched per request (1 to 100), defaults to 100
+    pub timeout: Option<Duration>, // Timeout for each RPC request, exceeding it fails with ScfsError::Timeout, defaults to the RpcClient's
+    pub include_description: bool, // Looks up each feature's description, defaults to true
+    pub custom_clusters: HashMap<String, String>, // Extra cluster name -> url merged with SCFS_URL_LOOKUPS, defaults to empty
+    pub commitment: CommitmentConfig, // Commitment of slot and feature account reads, defaults to confirmed
+    pub faux_local: bool, // Reports every feature Active(0) on the local cluster without querying it, defaults to false
+    pub allow_unknown_features: bool, // Accepts feature keys not in FEATURE_NAMES or custom_features (e.g. newer than the SDK), defaults to false
+    pub resolve_epochs: bool, // Fetches each cluster's epoch schedule as part of run, defaults to false
+    pub retry: Option<RetryPolicy>, // Retries of transient RPC failures, backing off exponentially with jitter, defaults to RetryPolicy::default() (None to not retry)
+}
+
+/// The feature key for a base58 key or a FEATURE_NAMES description
+fn feature_key_for(token: &str) -> Option<Pubkey> {
+    Pubkey::from_str(token)
+        .ok()
+        .or_else(|| SCFS_FEATURE_KEYS.get(token).copied())
+}
+
+/// The cluster name for a cluster name or Solana CLI moniker
+/// (d, t, m, mainnet-beta, l or localhost)
+fn cluster_for(token: &str) -> Option<String> {
+    match token {
+        "d" => Some(SCFS_DEVNET.clone()),
+        "t" => Some(SCFS_TESTNET.clone()),
+        "m" | "mainnet-beta" => Some(SCFS_MAINNET.clone()),
+        "l" | "localhost" => Some(SCFS_LOCAL.clone()),
+        name if SCFS_URL_LOOKUPS.contains_key(name) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Fails with every name of 'names' shared by several features,
+/// those features can only be given by their keys
+fn check_unambiguous<S: AsRef<str>>(names: &[S]) -> ScfsResult<()> {
+    let ambiguous: Vec<String> = names
+        .iter()
+        .filter(|name| SCFS_AMBIGUOUS_FEATURE_NAMES.contains(name.as_ref()))
+        .map(|name| name.as_ref().to_string())
+        .collect();
+    if !ambiguous.is_empty() {
+        return Err(ScfsError::AmbiguousFeatureNameError(ambiguous));
+    }
+    Ok(())
+}
+
+/// The feature keys of FEATURE_NAMES descriptions, failing with every
+/// name that is not a feature's or is shared by several features
+fn feature_keys_for_names<S: AsRef<str>>(names: &[S]) -> ScfsResult<Vec<Pubkey>> {
+    check_unambiguous(names)?;
+    let unknown: Vec<String> = names
+        .iter()
+        .filter(|name| !SCFS_FEATURE_KEYS.contains_key(name.as_ref()))
+        .map(|name| name.as_ref().to_string())
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ScfsError::UnrecognizedCriteriaTypeError {
+            ctype: "feature",
+            element: unknown,
+        });
+    }
+    Ok(names
+        .iter()
+        .map(|name| SCFS_FEATURE_KEYS[name.as_ref()])
+        .collect())
+}
+
+/// Estimated wall clock time of a slot from the cluster's genesis time
+/// and slot duration, e.g. DEFAULT_MS_PER_SLOT, skipped slots not accounted for
+pub fn estimated_slot_time(
+    slot: Slot,
+    genesis_time: UnixTimestamp,
+    slot_duration: Duration,
+) -> UnixTimestamp {
+    genesis_time + (slot_duration.as_millis() * slot as u128 / 1000) as UnixTimestamp
+}
+
+/// Splits 'items' into the first occurrence of each, in order,
+/// and every later occurrence
+fn split_duplicates<T: Clone + Eq + std::hash::Hash>(items: &[T]) -> (Vec<T>, Vec<T>) {
+    let mut seen = HashSet::<&T>::new();
+    let (unique, repeated): (Vec<&T>, Vec<&T>) = items.iter().partition(|i| seen.insert(*i));
+    (
+        unique.into_iter().cloned().collect(),
+        repeated.into_iter().cloned().collect(),
+    )
+}
+
+/// Looks up each comma separated token of 'text', skipping empty ones,
+/// failing on the first token 'lookup' does not recognize as a 'ctype'
+fn parse_tokens<T>(
+    ctype: &'static str,
+    text: &str,
+    lookup: impl Fn(&str) -> Option<T>,
+) -> ScfsResult<Vec<T>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            lookup(token).ok_or_else(|| ScfsError::UnrecognizedCriteriaTypeError {
+                ctype,
+                element: vec![token.to_string()],
+            })
+        })
+        .collect()
 }
 
 impl ScfsCriteria {
     fn get_clusters(&self) -> &Option<Vec<String>> {
         &self.clusters
     }
+
+    /// The url for a cluster, its custom_clusters url if it
+    /// is a custom cluster, otherwise as cluster_url
+    pub fn cluster_url(&self, cluster: &str) -> Option<String> {
+        self.custom_clusters
+            .get(cluster)
+            .cloned()
+            .or_else(|| cluster_url(cluster))
+    }
+
+    /// Whether the criteria accepts 'key' as a feature, one of FEATURE_NAMES
+    /// or custom_features, or any key when allow_unknown_features is set
+    fn knows_feature(&self, key: &Pubkey) -> bool {
+        self.allow_unknown_features
+            || SCFS_FEATURE_NAMES.contains_key(key)
+            || self.custom_features.contains_key(key)
+    }
+
+    /// Limits the criteria to the public clusters (devnet,
+    /// testnet and mainnet), leaving out the faux local cluster
+    pub fn public_clusters(mut self) -> Self {
+        self.clusters = Some(SCFS_PUBLIC_CLUSTER_LIST.to_vec());
+        self
+    }
+
+    /// Parses criteria from comma separated user input, 'features' as base58
+    /// keys or descriptions (one containing a comma only by its key) and
+    /// 'clusters' as cluster names or monikers (e.g. d, mainnet-beta). Empty
+    /// input keeps the default of all features or clusters, an unrecognized
+    /// token (including a key that is not a feature's) fails naming that token
+    /// as does a description shared by several features
+    pub fn parse(features: &str, clusters: &str) -> ScfsResult<ScfsCriteria> {
+        let mut criteria = ScfsCriteria::default();
+        check_unambiguous(&features.split(',').map(str::trim).collect::<Vec<_>>())?;
+        let features = parse_tokens("feature", features, |token| {
+            feature_key_for(token).filter(|key| criteria.knows_feature(key))
+        })?;
+        if !features.is_empty() {
+            criteria.features = Some(features);
+        }
+        let clusters = parse_tokens("cluster", clusters, cluster_for)?;
+        if !clusters.is_empty() {
+            criteria.clusters = Some(clusters);
+        }
+        Ok(criteria)
+    }
+
+    /// Criteria for the features named, by their FEATURE_NAMES description (e.g.
+    /// "secp256k1 program"), failing with every name that is not a feature's
+    /// or is shared by several features
+    pub fn from_feature_names(names: &[&str]) -> ScfsResult<ScfsCriteria> {
+        Ok(ScfsCriteria {
+            features: Some(feature_keys_for_names(names)?),
+            ..Default::default()
+        })
+    }
+
+    /// A builder starting from the default criteria
+    pub fn builder() -> ScfsCriteriaBuilder {
+        ScfsCriteriaBuilder::default()
+    }
+
+    /// Loads criteria from a YAML config file, omitted entries keep their defaults:
+    ///
+    /// ```yaml
+    /// features:       # feature names (descriptions) or base58 keys
+    ///   - secp256k1 program
+    /// clusters:       # cluster names
+    ///   - devnet
+    /// keep_decoded: false
+    /// batch_size: 50  # features per request, 1 to 100
+    /// timeout: 30     # seconds per RPC request
+    /// ```
+    pub fn from_config_file(path: impl AsRef<Path>) -> ScfsResult<ScfsCriteria> {
+        let path_str = path.as_ref().display().to_string();
+        let entry_error = |entry: &str, reason: &str| ScfsError::ConfigEntryError {
+            path: path_str.clone(),
+            entry: entry.to_string(),
+            reason: reason.to_string(),
+        };
+        let text = fs::read_to_string(&path).map_err(|source| ScfsError::ConfigReadError {
+            path: path_str.clone(),
+            source,
+        })?;
+        let docs = YamlLoader::load_from_str(&text)
+            .map_err(|e| entry_error("document", &e.to_string()))?;
+        let config = match docs.first() {
+            Some(config @ Yaml::Hash(_)) => config,
+            _ => return Err(entry_error("document", "expected a mapping")),
+        };
+        let mut criteria = ScfsCriteria::default();
+        let strings = |key: &str| -> ScfsResult<Option<Vec<String>>> {
+            match &config[key] {
+                Yaml::BadValue => Ok(None),
+                Yaml::Array(entries) => entries
+                    .iter()
+                    .map(|e| match e {
+                        Yaml::String(s) => Ok(s.clone()),
+                        _ => Err(entry_error(
+                            key,
+                            &format!("expected a string, found {:?}", e),
+                        )),
+                    })
+                    .collect::<ScfsResult<Vec<String>>>()
+                    .map(Some),
+                _ => Err(entry_error(key, "expected a list")),
+            }
+        };
+        if let Some(features) = strings("features")? {
+            criteria.features = Some(
+                features
+                    .iter()
+                    .map(|f| match feature_key_for(f) {
+                        Some(key) if criteria.knows_feature(&key) => Ok(key),
+                        None if SCFS_AMBIGUOUS_FEATURE_NAMES.contains(f) => Err(entry_error(
+                            &format!("feature '{}'", f),
+                            "name shared by several features, use its key",
+                        )),
+                        _ => Err(entry_error(&format!("feature '{}'", f), "unknown feature")),
+                    })
+                    .collect::<ScfsResult<Vec<Pubkey>>>()?,
+            );
+        }
+        if let Some(clusters) = strings("clusters")? {
+            if let Some(bad) = clusters.iter().find(|c| !SCFS_URL_LOOKUPS.contains_key(*c)) {
+                return Err(entry_error(
+                    &format!("cluster '{}'", bad),
+                    "unknown cluster",
+                ));
+            }
+            criteria.clusters = Some(clusters);
+        }
+        match &config["keep_decoded"] {
+            Yaml::BadValue => {}
+            Yaml::Boolean(keep) => criteria.keep_decoded = *keep,
+            _ => return Err(entry_error("keep_decoded", "expected true or false")),
+        }
+        match &config["batch_size"] {
+            Yaml::BadValue => {}
+            Yaml::Integer(size) if (1..=100).contains(size) => criteria.batch_size = *size as usize,
+            _ => {
+                return Err(entry_error(
+                    "batch_size",
+                    "expected an integer from 1 to 100",
+                ))
+            }
+        }
+        match config["timeout"]
+            .as_f64()
+            .or(config["timeout"].as_i64().map(|t| t as f64))
+        {
+            Some(secs) if secs > 0.0 => criteria.timeout = Some(Duration::from_secs_f64(secs)),
+            None if config["timeout"].is_badvalue() => {}
+            _ => {
+                return Err(entry_error(
+                    "timeout",
+                    "expected a positive number of seconds",
+                ))
+            }
+        }
+        Ok(criteria)
+    }
+
+    /// Whether rows carry feature descriptions, skipping the
+    /// lookup and the exported description column when not
+    pub fn include_description(mut self, include: bool) -> Self {
+        self.include_description = include;
+        self
+    }
+
+    /// Parses feature keys, one base58 key per line, skipping
+    /// blank lines and '#' comments
+    pub fn features_from_reader(r: impl BufRead) -> ScfsResult<Vec<Pubkey>> {
+        let mut features = Vec::<Pubkey>::new();
+        for (index, line) in r.lines().enumerate() {
+            let line = line.map_err(|source| ScfsError::FeatureListReadError {
+                line: index + 1,
+                source,
+            })?;
+            let key = line.split('#').next().unwrap().trim();
+            if key.is_empty() {
+                continue;
+            }
+            features.push(Pubkey::from_str(key).map_err(|_| {
+                ScfsError::InvalidFeatureKeyError {
+                    line: index + 1,
+                    key: key.to_string(),
+                }
+            })?);
+        }
+        Ok(features)
+    }
 }
 
 impl Default for ScfsCriteria {
@@ -99,34 +468,246 @@ impl Default for ScfsCriteria {
         Self {
             features: Some(SCFS_FEATURE_PKS.to_vec()),
             clusters: Some(SCFS_CLUSTER_LIST.to_vec()),
+            keep_decoded: false,
+            custom_features: HashMap::new(),
+            batch_size: 100,
+            timeout: None,
+            include_description: true,
+            custom_clusters: HashMap::new(),
+            commitment: CommitmentConfig::confirmed(),
+            faux_local: false,
+            allow_unknown_features: false,
+            resolve_epochs: false,
+            retry: Some(RetryPolicy::default()),
         }
     }
 }
 
-/// Cluster feature status indicator
-#[derive(Debug, Clone, PartialEq)]
+/// Chainable construction of ScfsCriteria, fields not set keep their
+/// ScfsCriteria::default() values
+#[derive(Clone, Debug, Default)]
+pub struct ScfsCriteriaBuilder {
+    criteria: ScfsCriteria,
+    features: Option<Vec<Pubkey>>,
+    feature_names: Vec<String>,
+}
+
+impl ScfsCriteriaBuilder {
+    /// Limits the features to query status on by key
+    pub fn features(mut self, features: &[Pubkey]) -> Self {
+        self.features = Some(features.to_vec());
+        self
+    }
+
+    /// Limits the features to query status on by FEATURE_NAMES description,
+    /// in addition to any set by features
+    pub fn feature_names(mut self, names: &[&str]) -> Self {
+        self.feature_names = names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Limits the clusters to query the features on
+    pub fn clusters(mut self, clusters: &[&str]) -> Self {
+        self.criteria.clusters = Some(clusters.iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// The commitment slot and feature account reads are made at
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.criteria.commitment = commitment;
+        self
+    }
+
+    /// Resolves the feature names and validates the criteria as ScfsMatrix::new does
+    pub fn build(self) -> ScfsResult<ScfsCriteria> {
+        let mut criteria = self.criteria;
+        if self.features.is_some() || !self.feature_names.is_empty() {
+            let mut features = self.features.unwrap_or_default();
+            features.extend(feature_keys_for_names(&self.feature_names)?);
+            criteria.features = Some(features);
+        }
+        ScfsMatrix::validate_and_complete_criteria(&criteria)
+    }
+}
+
+/// File formats for ScfsMatrix exports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// Cluster feature status indicator, serialized as
+/// `{"status": "active", "slot": 12345}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ScfsStatusRecord", into = "ScfsStatusRecord")]
 pub enum ScfsStatus {
+    /// No valid feature account exists on the cluster
     Inactive,
+    /// Feature account exists but its activated_at is None
     Pending,
+    /// Feature account activated_at the Slot
     Active(Slot),
 }
 
-#[derive(Debug)]
+/// Displays as `inactive`, `pending` or `active @ <slot>`, e.g. `active @ 12345`.
+/// The format is stable for parsing
+impl std::fmt::Display for ScfsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScfsStatus::Inactive => write!(f, "inactive"),
+            ScfsStatus::Pending => write!(f, "pending"),
+            ScfsStatus::Active(slot) => write!(f, "active @ {}", slot),
+        }
+    }
+}
+
+/// Internally tagged form of ScfsStatus, serde can't tag the Active newtype
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum ScfsStatusRecord {
+    Inactive,
+    Pending,
+    Active { slot: Slot },
+}
+
+impl From<ScfsStatus> for ScfsStatusRecord {
+    fn from(status: ScfsStatus) -> Self {
+        match status {
+            ScfsStatus::Inactive => Self::Inactive,
+            ScfsStatus::Pending => Self::Pending,
+            ScfsStatus::Active(slot) => Self::Active { slot },
+        }
+    }
+}
+
+impl From<ScfsStatusRecord> for ScfsStatus {
+    fn from(record: ScfsStatusRecord) -> Self {
+        match record {
+            ScfsStatusRecord::Inactive => Self::Inactive,
+            ScfsStatusRecord::Pending => Self::Pending,
+            ScfsStatusRecord::Active { slot } => Self::Active(slot),
+        }
+    }
+}
+
+/// Serializes a Pubkey as its base58 string
+fn serialize_pubkey<S: Serializer>(key: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(key)
+}
+
+/// Deserializes a Pubkey from its base58 string
+fn deserialize_pubkey<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    let key = String::deserialize(deserializer)?;
+    Pubkey::from_str(&key).map_err(serde::de::Error::custom)
+}
+
+/// Symbol and color a renderer shows for a status
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScfsStatusStyle {
+    pub symbol: String,
+    pub color: String,
+}
+
+impl ScfsStatusStyle {
+    pub fn new(symbol: &str, color: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            color: color.to_string(),
+        }
+    }
+}
+
+/// Styles of each ScfsStatus category used by the matrix renderers,
+/// defaulting to green, amber and red status names
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScfsStatusStyles {
+    pub active: ScfsStatusStyle,
+    pub pending: ScfsStatusStyle,
+    pub inactive: ScfsStatusStyle,
+}
+
+impl ScfsStatusStyles {
+    /// The style of the status's category
+    pub fn style_for(&self, status: &ScfsStatus) -> &ScfsStatusStyle {
+        match status {
+            ScfsStatus::Inactive => &self.inactive,
+            ScfsStatus::Pending => &self.pending,
+            ScfsStatus::Active(_) => &self.active,
+        }
+    }
+}
+
+impl Default for ScfsStatusStyles {
+    fn default() -> Self {
+        Self {
+            active: ScfsStatusStyle::new("active", "#2e7d32"),
+            pending: ScfsStatusStyle::new("pending", "#ff8f00"),
+            inactive: ScfsStatusStyle::new("inactive", "#c62828"),
+        }
+    }
+}
+
+/// Boxed get_features predicate, as built by the ScfsMatrix combinators
+pub type ScfsRowPredicate = Box<dyn Fn(&ScfsRow) -> bool>;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScfsRow {
+    #[serde(
+        rename = "feature",
+        serialize_with = "serialize_pubkey",
+        deserialize_with = "deserialize_pubkey"
+    )]
     feature_key: Pubkey,
+    #[serde(rename = "status")]
     feature_status: Vec<ScfsStatus>,
+    #[serde(rename = "description")]
     feature_description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    raw_features: Vec<Option<Feature>>,
+}
+
+// Feature is not Clone, its accounts are copied field by field
+impl Clone for ScfsRow {
+    fn clone(&self) -> Self {
+        Self {
+            feature_key: self.feature_key,
+            feature_status: self.feature_status.clone(),
+            feature_description: self.feature_description.clone(),
+            raw_features: (0..self.raw_features.len())
+                .map(|column| self.raw_feature(column))
+                .collect(),
+        }
+    }
 }
 
 impl ScfsRow {
-    /// New ScfsRow with key and description
+    /// New ScfsRow with key and description, the description
+    /// falls back to the base58 key when empty
     fn new(feature_key: Pubkey, feature_description: String) -> Self {
+        let feature_description = if feature_description.is_empty() {
+            feature_key.to_string()
+        } else {
+            feature_description
+        };
+        Self {
+            feature_key,
+            feature_description,
+            feature_status: Vec::<ScfsStatus>::new(),
+            raw_features: Vec::<Option<Feature>>::new(),
+        }
+    }
+
+    /// New ScfsRow without a description
+    fn undescribed(feature_key: Pubkey) -> Self {
         Self {
             feature_key,
-            feature_description: feature_description,
+            feature_description: String::new(),
             feature_status: Vec::<ScfsStatus>::new(),
+            raw_features: Vec::<Option<Feature>>::new(),
         }
     }
+
     pub fn key(&self) -> &Pubkey {
         &self.feature_key
     }
@@ -136,17 +717,95 @@ impl ScfsRow {
     pub fn desc(&self) -> &String {
         &self.feature_description
     }
+    /// The decoded feature account for the cluster column, retained
+    /// only when the criteria sets keep_decoded and the account exists
+    pub fn raw_feature(&self, column: usize) -> Option<Feature> {
+        self.raw_features.get(column)?.as_ref().map(|f| Feature {
+            activated_at: f.activated_at,
+        })
+    }
     // Borrow the feature status
     fn push_feature_status(&mut self, status: ScfsStatus) {
         self.feature_status.push(status)
     }
 }
 
+/// A feature's status, activation slot and activation time on a cluster,
+/// timings are None unless the feature is Active
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterTimelineEntry {
+    pub cluster: String,
+    pub status: ScfsStatus,
+    pub activation_slot: Option<Slot>,
+    pub activation_time: Option<UnixTimestamp>,
+}
+
+/// A feature's activation across each queried cluster
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureTimeline {
+    pub feature_key: Pubkey,
+    pub entries: Vec<ClusterTimelineEntry>,
+}
+
+/// Statuses of a matrix by feature and cluster, kept to compare later runs against.
+/// Persist the matrix's ScfsMatrixProjection and rebuild the snapshot from it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScfsSnapshot {
+    statuses: HashMap<(Pubkey, String), ScfsStatus>,
+}
+
+impl ScfsSnapshot {
+    /// The status of a feature on a cluster when the snapshot was taken
+    pub fn status(&self, feature: &Pubkey, cluster: &str) -> Option<&ScfsStatus> {
+        self.statuses.get(&(*feature, cluster.to_string()))
+    }
+}
+
+/// Serializable results of a ScfsMatrix, e.g. to cache them to disk,
+/// each row's statuses are in the order of 'clusters'
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScfsMatrixProjection {
+    pub clusters: Vec<String>,
+    pub current_slots: HashMap<String, Slot>,
+    pub incomplete: bool,
+    pub rows: Vec<ScfsRow>,
+}
+
+impl From<&ScfsMatrixProjection> for ScfsSnapshot {
+    fn from(projection: &ScfsMatrixProjection) -> Self {
+        let mut statuses = HashMap::<(Pubkey, String), ScfsStatus>::new();
+        for row in &projection.rows {
+            for (cluster, status) in projection.clusters.iter().zip(row.status()) {
+                statuses.insert((*row.key(), cluster.clone()), status.clone());
+            }
+        }
+        ScfsSnapshot { statuses }
+    }
+}
+
+/// Transports (including injected RpcClients) by cluster name, only the clusters are shown
+#[derive(Default)]
+struct ScfsTransports(HashMap<String, Arc<dyn ScfsTransport>>);
+
+impl std::fmt::Debug for ScfsTransports {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.0.keys()).finish()
+    }
+}
+
 #[derive(Debug)]
 pub struct ScfsMatrix {
     criteria: ScfsCriteria,
     rows: Vec<ScfsRow>,
     query_set: Vec<Pubkey>,
+    current_slots: HashMap<String, Slot>,
+    epoch_schedules: HashMap<String, EpochSchedule>,
+    unknown_features: Vec<Pubkey>,
+    duplicate_features: Vec<Pubkey>,
+    duplicate_clusters: Vec<String>,
+    incomplete: bool,
+    local_refused: bool,
+    transports: ScfsTransports,
 }
 
 impl ScfsMatrix {
@@ -154,16 +813,61 @@ impl ScfsMatrix {
     /// ScfsCriteria (if None passed in) or configures
     /// to the provided ScfsCriteria after validating
     pub fn new(in_criteria: Option<ScfsCriteria>) -> ScfsResult<Self> {
-        let criteria = if let Some(c) = in_criteria {
-            Self::validate_and_complete_criteria(&c)?
+        Self::new_with_transports(in_criteria, HashMap::new())
+    }
+
+    /// Creates a new ScfsMatrix as for new, querying clusters with the
+    /// provided RpcClients (e.g. custom senders or mocks) by cluster name.
+    /// Clusters without one get a client to the cluster's url
+    pub fn new_with_clients(
+        in_criteria: Option<ScfsCriteria>,
+        clients: HashMap<String, Arc<RpcClient>>,
+    ) -> ScfsResult<Self> {
+        Self::new_with_transports(
+            in_criteria,
+            clients
+                .into_iter()
+                .map(|(cluster, client)| (cluster, client as Arc<dyn ScfsTransport>))
+                .collect(),
+        )
+    }
+
+    /// Creates a new ScfsMatrix as for new, fetching the slot and feature
+    /// accounts of clusters through the provided transports (e.g. mocks for
+    /// offline tests) by cluster name. Clusters without one use a RpcClient
+    pub fn new_with_transports(
+        in_criteria: Option<ScfsCriteria>,
+        transports: HashMap<String, Arc<dyn ScfsTransport>>,
+    ) -> ScfsResult<Self> {
+        let (criteria, duplicate_features, duplicate_clusters) = if let Some(c) = in_criteria {
+            (
+                Self::validate_and_complete_criteria(&c)?,
+                split_duplicates(c.features.as_deref().unwrap_or_default()).1,
+                split_duplicates(c.clusters.as_deref().unwrap_or_default()).1,
+            )
         } else {
-            ScfsCriteria::default()
+            (ScfsCriteria::default(), Vec::new(), Vec::new())
         };
         let (rows, query_set) = Self::build_rows(&criteria);
+        let unknown_features = query_set
+            .iter()
+            .filter(|f| {
+                !SCFS_FEATURE_NAMES.contains_key(f) && !criteria.custom_features.contains_key(f)
+            })
+            .cloned()
+            .collect();
         Ok(Self {
             criteria,
             rows,
             query_set,
+            current_slots: HashMap::new(),
+            epoch_schedules: HashMap::new(),
+            unknown_features,
+            duplicate_features,
+            duplicate_clusters,
+            incomplete: false,
+            local_refused: false,
+            transports: ScfsTransports(transports),
         })
     }
 
@@ -178,7 +882,17 @@ impl ScfsMatrix {
             .map(|f| {
                 let pk = f.clone();
                 query_set.push(pk.clone());
-                ScfsRow::new(pk, (&*FEATURE_NAMES.get(f).unwrap()).to_string())
+                if !criteria.include_description {
+                    return ScfsRow::undescribed(pk);
+                }
+                let description = match criteria.custom_features.get(f) {
+                    Some(d) => d.clone(),
+                    None => SCFS_FEATURE_NAMES
+                        .get(f)
+                        .cloned()
+                        .unwrap_or_else(|| SCFS_UNKNOWN_FEATURE.clone()),
+                };
+                ScfsRow::new(pk, description)
             })
             .collect();
         (rows, query_set)
@@ -191,13 +905,23 @@ impl ScfsMatrix {
             Err(ScfsError::NoCriteriaFeaturesError)
         } else {
             let mut bad_elements = Vec::<String>::new();
+            // Custom clusters can't shadow the predefined ones
+            if let Some(reserved) = in_criteria
+                .custom_clusters
+                .keys()
+                .find(|name| SCFS_URL_LOOKUPS.contains_key(*name))
+            {
+                return Err(ScfsError::ReservedClusterNameError(reserved.clone()));
+            }
             // Its ok to not have clusters but they must be
-            // a recognized cluster name
+            // a recognized or custom cluster name
             if let Some(clusters) = &in_criteria.clusters {
                 let matching = clusters
                     .iter()
                     .filter(|predicate| {
-                        if SCFS_URL_LOOKUPS.get(*predicate).is_some() {
+                        if SCFS_URL_LOOKUPS.contains_key(*predicate)
+                            || in_criteria.custom_clusters.contains_key(*predicate)
+                        {
                             true
                         } else {
                             bad_elements.push(predicate.to_string());
@@ -218,7 +942,7 @@ impl ScfsMatrix {
                 let matching = features
                     .iter()
                     .filter(|predicate| {
-                        if SCFS_FEATURE_PKS.contains(predicate) {
+                        if in_criteria.knows_feature(predicate) {
                             true
                         } else {
                             bad_elements.push(predicate.to_string());
@@ -238,7 +962,18 @@ impl ScfsMatrix {
                     ctype: "No features",
                 });
             }
-            Ok(in_criteria.clone())
+            if !(1..=100).contains(&in_criteria.batch_size) {
+                return Err(ScfsError::UnrecognizedCriteriaTypeError {
+                    element: vec![in_criteria.batch_size.to_string()],
+                    ctype: "batch_size",
+                });
+            }
+            // Repeated features and clusters would be queried and reported
+            // more than once, only their first occurrence is kept
+            let mut criteria = in_criteria.clone();
+            criteria.features = criteria.features.map(|f| split_duplicates(&f).0);
+            criteria.clusters = criteria.clusters.map(|c| split_duplicates(&c).0);
+            Ok(criteria)
         }
     }
 
@@ -248,62 +983,285 @@ impl ScfsMatrix {
         row.push_feature_status(status);
     }
 
-    /// Get the status of a particular feature account
-    fn status_from_account(account: Account) -> Option<ScfsStatus> {
-        feature::from_account(&account).map(|feature| match feature.activated_at {
-            None => ScfsStatus::Pending,
-            Some(activation_slot) => ScfsStatus::Active(activation_slot),
-        })
+    /// Get the status of a particular feature account, an account
+    /// that does not deserialize as a Feature yields None (Inactive)
+    fn status_from_feature(feature: &Option<Feature>) -> ScfsStatus {
+        match feature {
+            None => ScfsStatus::Inactive,
+            Some(Feature { activated_at: None }) => ScfsStatus::Pending,
+            Some(Feature {
+                activated_at: Some(activation_slot),
+            }) => ScfsStatus::Active(*activation_slot),
+        }
     }
 
-    /// Get account state and add to row
+    /// Get account state and add to row, retaining the
+    /// decoded feature if the criteria asks to keep it
     fn set_status_for_row(&mut self, row_index: usize, account: Option<Account>) {
-        let status = match account {
-            Some(a) => match ScfsMatrix::status_from_account(a) {
-                Some(s) => s,
-                None => ScfsStatus::Inactive,
-            },
-            None => ScfsStatus::Inactive,
-        };
+        let feature = account.and_then(|a| feature::from_account(&a));
+        let status = ScfsMatrix::status_from_feature(&feature);
+        if self.criteria.keep_decoded {
+            self.rows[row_index].raw_features.push(feature);
+        }
         self.push_to_row(row_index, status)
     }
 
-    /// Populate rows from cluster statusing
+    /// A new RpcClient to a cluster's url
+    fn client_for(&self, cluster: &str) -> Arc<RpcClient> {
+        let url = self.criteria.cluster_url(cluster).unwrap();
+        Arc::new(match self.criteria.timeout {
+            Some(timeout) => {
+                RpcClient::new_with_timeout_and_commitment(url, timeout, self.criteria.commitment)
+            }
+            None => RpcClient::new_with_commitment(url, self.criteria.commitment),
+        })
+    }
+
+    /// The transport fetching from a cluster, the one held for it
+    /// or else a RpcClient to the cluster's url
+    fn transport_for(&self, cluster: &String) -> Arc<dyn ScfsTransport> {
+        match self.transports.0.get(cluster) {
+            Some(transport) => transport.clone(),
+            None => self.client_for(cluster),
+        }
+    }
+
+    /// Captures the statuses of each feature and cluster
+    pub fn snapshot(&self) -> ScfsSnapshot {
+        ScfsSnapshot::from(&self.to_projection())
+    }
+
+    /// The (feature, cluster, old, new) status of every cell that changed since
+    /// 'previous', in row then cluster order. Cells not in 'previous' are skipped
+    pub fn diff_against(
+        &self,
+        previous: &ScfsSnapshot,
+    ) -> Vec<(Pubkey, String, ScfsStatus, ScfsStatus)> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let mut changes = Vec::<(Pubkey, String, ScfsStatus, ScfsStatus)>::new();
+        for row in &self.rows {
+            for (cluster, status) in clusters.iter().zip(row.status()) {
+                match previous.status(row.key(), cluster) {
+                    Some(old) if old != status => {
+                        changes.push((*row.key(), cluster.clone(), old.clone(), status.clone()))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        changes
+    }
+
+    /// The (feature, cluster) of every cell Active in 'prior' but no longer Active,
+    /// in row then cluster order. Features shouldn't deactivate, so these point
+    /// to a reorg or a different cluster behind the url
+    pub fn regressions_since(&self, prior: &ScfsSnapshot) -> Vec<(Pubkey, String)> {
+        self.diff_against(prior)
+            .into_iter()
+            .filter(|(_, _, old, new)| {
+                matches!(old, ScfsStatus::Active(_)) && !matches!(new, ScfsStatus::Active(_))
+            })
+            .map(|(key, cluster, _, _)| (key, cluster))
+            .collect()
+    }
+
+    /// Clusters where 'after' is active while 'before' is not, breaking the
+    /// invariant that 'after' only activates once 'before' has. A feature
+    /// missing from the matrix counts as not active
+    pub fn check_ordering(&self, before: Pubkey, after: Pubkey) -> Vec<String> {
+        let statuses = |key: Pubkey| {
+            self.rows
+                .iter()
+                .find(|r| r.key() == &key)
+                .map(|r| r.status().as_slice())
+                .unwrap_or_default()
+        };
+        let (before, after) = (statuses(before), statuses(after));
+        self.criteria
+            .clusters
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(index, _)| {
+                matches!(after.get(*index), Some(ScfsStatus::Active(_)))
+                    && !matches!(before.get(*index), Some(ScfsStatus::Active(_)))
+            })
+            .map(|(_, cluster)| cluster.clone())
+            .collect()
+    }
+
+    /// The default clusters the criteria leaves out, in SCFS_CLUSTER_LIST order
+    pub fn unqueried_clusters(&self) -> Vec<String> {
+        let queried = self.criteria.clusters.as_deref().unwrap_or_default();
+        SCFS_CLUSTER_LIST
+            .iter()
+            .filter(|c| !queried.contains(c))
+            .cloned()
+            .collect()
+    }
+
+    /// Features of the matrix whose account does not deserialize as a Feature on
+    /// 'cluster', i.e. features that cluster's version does not know. The faux
+    /// local cluster knows every feature, as does a local cluster without a
+    /// validator to connect to, which run treats as the faux one
+    pub async fn validate_features_on_cluster(&self, cluster: &str) -> ScfsResult<Vec<Pubkey>> {
+        if cluster == SCFS_LOCAL.as_str() && self.criteria.faux_local {
+            return Ok(Vec::new());
+        }
+        if !self.transports.0.contains_key(cluster) && self.criteria.cluster_url(cluster).is_none()
+        {
+            return Err(ScfsError::UnrecognizedCriteriaTypeError {
+                element: vec![cluster.to_string()],
+                ctype: "cluster",
+            });
+        }
+        let rpcclient = self.transport_for(&cluster.to_string());
+        let mut unknown = Vec::<Pubkey>::new();
+        for keys in self.query_set.chunks(self.criteria.batch_size) {
+            let accounts = match rpcclient
+                .get_multiple_accounts(keys, self.criteria.commitment)
+                .await
+                .map_err(|e| ScfsError::RpcError {
+                    cluster: cluster.to_string(),
+                    source: Box::new(e),
+                }) {
+                Err(e) if cluster == SCFS_LOCAL.as_str() && e.is_connection_failure() => {
+                    return Ok(Vec::new())
+                }
+                accounts => accounts?,
+            };
+            unknown.extend(
+                keys.iter()
+                    .zip(accounts)
+                    .filter(|(_, account)| {
+                        account.as_ref().and_then(feature::from_account).is_none()
+                    })
+                    .map(|(key, _)| *key),
+            );
+        }
+        Ok(unknown)
+    }
+
+    /// The url queried for each cluster of the criteria
+    pub fn resolved_urls(&self) -> HashMap<String, String> {
+        let mut urls = HashMap::<String, String>::new();
+        for cluster in self.criteria.clusters.iter().flatten() {
+            let url = match self.transports.0.get(cluster).and_then(|t| t.url()) {
+                Some(url) => url,
+                None => self.criteria.cluster_url(cluster).unwrap(),
+            };
+            urls.insert(cluster.clone(), url);
+        }
+        urls
+    }
+
+    /// Fetches the current slot and the query set's accounts from a
+    /// cluster, None for the accounts of the faux (always active) local cluster.
+    /// Requests failing transiently are retried by 'retry', see retry_transient
+    async fn fetch_cluster(
+        cluster: &str,
+        transport: Option<Arc<dyn ScfsTransport>>,
+        query_set: &[Pubkey],
+        batch_size: usize,
+        commitment: CommitmentConfig,
+        retry: Option<RetryPolicy>,
+        timeout: Option<Duration>,
+    ) -> ScfsResult<(Slot, Option<Vec<Option<Account>>>)> {
+        let Some(rcpclient) = transport else {
+            return Ok((0, None));
+        };
+        let slot =
+            retry_transient(cluster, retry, timeout, || rcpclient.get_slot(commitment)).await?;
+        // get_multiple_accounts is now capped at 100 elements so we
+        // need to break up the feature query set
+        let mut accounts = Vec::<Option<Account>>::with_capacity(query_set.len());
+        for iset in query_set.chunks(batch_size) {
+            accounts.extend(
+                retry_transient(cluster, retry, timeout, || {
+                    rcpclient.get_multiple_accounts(iset, commitment)
+                })
+                .await?,
+            );
+        }
+        Ok((slot, Some(accounts)))
+    }
+
+    /// Populate rows from cluster statusing, the clusters are queried
+    /// concurrently and their columns added in cluster order. On a failed
+    /// cluster the rows keep the columns of the clusters before it and the
+    /// matrix is marked incomplete. A local cluster without a validator to
+    /// connect to is treated as the faux local cluster
     async fn process_cluster(
         &mut self,
         query_set: &Vec<Pubkey>,
         cluster_ref: &Option<Vec<String>>,
+        mut on_column: impl FnMut(&str, &[ScfsStatus]),
     ) -> ScfsResult<()> {
+        self.incomplete = false;
+        self.local_refused = false;
         if let Some(clusters) = cluster_ref {
-            for cluster in clusters {
-                match cluster.as_str() {
-                    "local" => {
-                        let mut index = 0usize;
-                        for _ in query_set {
-                            self.push_to_row(index, ScfsStatus::Active(0));
-                            index += 1
+            let (batch_size, commitment, retry, timeout) = (
+                self.criteria.batch_size,
+                self.criteria.commitment,
+                self.criteria.retry,
+                self.criteria.timeout,
+            );
+            let mut fetches: stream::FuturesUnordered<_> = clusters
+                .iter()
+                .enumerate()
+                .map(|(index, cluster)| {
+                    let transport = if *cluster == *SCFS_LOCAL && self.criteria.faux_local {
+                        None
+                    } else {
+                        Some(self.transport_for(cluster))
+                    };
+                    ScfsMatrix::fetch_cluster(
+                        cluster, transport, query_set, batch_size, commitment, retry, timeout,
+                    )
+                    .map(move |fetched| (index, fetched))
+                })
+                .collect();
+            // Columns are reported as each cluster completes but merged as soon
+            // as those before them are, so a deadline keeps the columns of the
+            // clusters gathered in time
+            let mut completed = BTreeMap::new();
+            for (index, cluster) in clusters.iter().enumerate() {
+                while !completed.contains_key(&index) {
+                    let (done, fetched) = fetches.next().await.unwrap();
+                    let fetched = match fetched {
+                        Err(e) if clusters[done] == *SCFS_LOCAL && e.is_connection_failure() => {
+                            self.local_refused = true;
+                            Ok((0, None))
                         }
+                        fetched => fetched,
+                    };
+                    if let Ok((_, accounts)) = &fetched {
+                        on_column(&clusters[done], &Self::column_statuses(accounts, query_set));
+                    }
+                    completed.insert(done, fetched);
+                }
+                let (slot, accounts) = match completed.remove(&index).unwrap() {
+                    Ok(fetched) => fetched,
+                    Err(e) => {
+                        self.incomplete = true;
+                        return Err(e);
                     }
-                    _ => {
-                        let rcpclient =
-                            RpcClient::new(SCFS_URL_LOOKUPS.get(cluster).unwrap().clone());
-
-                        // get_multiple_accounts is now capped at 100 elements so we
-                        // need to break up the feature query set
-                        let dst: Vec<Vec<Pubkey>> =
-                            query_set.chunks(100).map(|s| s.into()).collect();
-                        let mut counter = 0usize;
-                        for iset in dst {
-                            for (_, account) in rcpclient
-                                .get_multiple_accounts(&iset)
-                                .await
-                                .unwrap()
-                                .into_iter()
-                                .enumerate()
-                            {
-                                self.set_status_for_row(counter, account);
-                                counter = counter + 1;
+                };
+                self.current_slots.insert(cluster.clone(), slot);
+                match accounts {
+                    None => {
+                        for index in 0..query_set.len() {
+                            if self.criteria.keep_decoded {
+                                self.rows[index].raw_features.push(Some(Feature {
+                                    activated_at: Some(0),
+                                }));
                             }
+                            self.push_to_row(index, ScfsStatus::Active(0));
+                        }
+                    }
+                    Some(accounts) => {
+                        for (counter, account) in accounts.into_iter().enumerate() {
+                            self.set_status_for_row(counter, account);
                         }
                     }
                 }
@@ -312,16 +1270,101 @@ impl ScfsMatrix {
         Ok(())
     }
 
+    /// The statuses of a fetched cluster column, Active(0) throughout
+    /// for the faux local cluster
+    fn column_statuses(
+        accounts: &Option<Vec<Option<Account>>>,
+        query_set: &[Pubkey],
+    ) -> Vec<ScfsStatus> {
+        match accounts {
+            None => vec![ScfsStatus::Active(0); query_set.len()],
+            Some(accounts) => accounts
+                .iter()
+                .map(|account| {
+                    ScfsMatrix::status_from_feature(
+                        &account.as_ref().and_then(feature::from_account),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Installs or replaces the status column of a cluster in the criteria,
+    /// e.g. when clusters are queried on separate schedules. Columns of
+    /// preceding clusters not yet set read as Inactive
+    pub fn set_cluster_column(
+        &mut self,
+        cluster: &str,
+        statuses: Vec<ScfsStatus>,
+    ) -> ScfsResult<()> {
+        let index = self
+            .criteria
+            .clusters
+            .iter()
+            .flatten()
+            .position(|c| c == cluster)
+            .ok_or_else(|| ScfsError::UnrecognizedCriteriaTypeError {
+                ctype: "cluster",
+                element: vec![cluster.to_string()],
+            })?;
+        if statuses.len() != self.rows.len() {
+            return Err(ScfsError::ColumnLengthError {
+                expected: self.rows.len(),
+                found: statuses.len(),
+            });
+        }
+        for (row, status) in self.rows.iter_mut().zip(statuses) {
+            if row.feature_status.len() <= index {
+                row.feature_status.resize(index + 1, ScfsStatus::Inactive);
+            }
+            row.feature_status[index] = status;
+        }
+        Ok(())
+    }
+
     /// Gets the internal query set
     fn get_query_set(&self) -> &Vec<Pubkey> {
         &self.query_set
     }
 
-    /// Run the matrix
+    /// Run the matrix, failing with ScfsError::RpcError if a cluster
+    /// can't be queried
     pub async fn run(&mut self) -> ScfsResult<()> {
-        let qs = self.get_query_set().clone();
+        self.run_with_progress(|_, _| {}).await
+    }
+
+    /// Run the matrix as for run, calling 'cb' with each cluster's name and
+    /// column of statuses as soon as that cluster completes, in completion order
+    pub async fn run_with_progress<F: FnMut(&str, &[ScfsStatus])>(
+        &mut self,
+        cb: F,
+    ) -> ScfsResult<()> {
+        let qs = self.get_query_set().clone();
         let csref = self.get_criteria().get_clusters().clone();
-        self.process_cluster(&qs, &csref).await
+        self.process_cluster(&qs, &csref, cb).await?;
+        if self.criteria.resolve_epochs {
+            self.resolve_epochs().await?;
+        }
+        Ok(())
+    }
+
+    /// Run the matrix giving up once 'deadline' passes, the rows then hold
+    /// the statuses gathered before it and the matrix is marked incomplete.
+    /// Resolving epochs, when the criteria asks for it, is within the deadline
+    pub async fn run_with_deadline(&mut self, deadline: Duration) -> ScfsResult<()> {
+        match tokio::time::timeout(deadline, self.run_with_progress(|_, _| {})).await {
+            Ok(result) => result,
+            Err(_) => {
+                self.incomplete = true;
+                Err(ScfsError::DeadlineExceeded(deadline))
+            }
+        }
+    }
+
+    /// True when the last run failed on a cluster or, for run_with_deadline,
+    /// ran out of time
+    pub fn is_incomplete(&self) -> bool {
+        self.incomplete
     }
 
     /// Retrieve criteria used in processing
@@ -334,6 +1377,69 @@ impl ScfsMatrix {
         &self.rows
     }
 
+    /// Features of the criteria not in FEATURE_NAMES or custom_features,
+    /// accepted by allow_unknown_features and described as SCFS_UNKNOWN_FEATURE
+    pub fn unknown_features(&self) -> &[Pubkey] {
+        &self.unknown_features
+    }
+
+    /// Features repeated in the criteria, once per repeat, dropped
+    /// from the matrix in favor of their first occurrence
+    pub fn duplicate_features(&self) -> &[Pubkey] {
+        &self.duplicate_features
+    }
+
+    /// Clusters repeated in the criteria, once per repeat, dropped
+    /// from the matrix in favor of their first occurrence
+    pub fn duplicate_clusters(&self) -> &[String] {
+        &self.duplicate_clusters
+    }
+
+    /// Retrieve the row of a feature
+    pub fn get_row(&self, key: &Pubkey) -> Option<&ScfsRow> {
+        self.rows.iter().find(|row| row.key() == key)
+    }
+
+    /// Retrieve the status of a feature on a cluster of the criteria
+    pub fn get_status(&self, key: &Pubkey, cluster: &str) -> Option<&ScfsStatus> {
+        let column = self.cluster_column(cluster).ok()?;
+        self.get_row(key)?.status().get(column)
+    }
+
+    /// Orders the rows by description, then key
+    pub fn sort_by_name(&mut self) {
+        self.rows
+            .sort_by(|a, b| a.desc().cmp(b.desc()).then(a.key().cmp(b.key())));
+        self.reorder_query_set();
+    }
+
+    /// Orders the rows by activation slot on the cluster, rows Pending
+    /// and then Inactive there following the Active ones
+    pub fn sort_by_activation(&mut self, cluster: &str) -> ScfsResult<()> {
+        let column = self.cluster_column(cluster)?;
+        self.rows.sort_by_key(|row| match row.status().get(column) {
+            Some(ScfsStatus::Active(slot)) => (0, *slot),
+            Some(ScfsStatus::Pending) => (1, 0),
+            Some(ScfsStatus::Inactive) => (2, 0),
+            None => (3, 0),
+        });
+        self.reorder_query_set();
+        Ok(())
+    }
+
+    // Keep the query set in row order, cluster columns are added by row index
+    fn reorder_query_set(&mut self) {
+        self.query_set = self.rows.iter().map(|row| *row.key()).collect();
+    }
+
+    /// Maps each feature key to its description
+    pub fn descriptions(&self) -> HashMap<Pubkey, &str> {
+        self.rows
+            .iter()
+            .map(|r| (*r.key(), r.desc().as_str()))
+            .collect()
+    }
+
     /// Convenient predicate returns true for any
     /// row when used in get_features filtering
     pub fn all(_: &ScfsRow) -> bool {
@@ -380,6 +1486,82 @@ impl ScfsMatrix {
         return row.status().contains(&ScfsStatus::Inactive);
     }
 
+    /// Convenient predicate returns true for any
+    /// row where a cluster return pending, i.e. the
+    /// feature account exists but is not yet activated,
+    /// when used in get_features filtering, same as any_pending
+    pub fn pending_somewhere(row: &ScfsRow) -> bool {
+        Self::any_pending(row)
+    }
+
+    /// Convenient predicate returns true for any
+    /// row where a cluster return pending
+    /// when used in get_features filtering
+    pub fn any_pending(row: &ScfsRow) -> bool {
+        row.status().contains(&ScfsStatus::Pending)
+    }
+
+    /// Convenient predicate returns true if all
+    /// cluster return pending when used in get_features filtering
+    pub fn all_pending(row: &ScfsRow) -> bool {
+        row.status().iter().all(|s| s == &ScfsStatus::Pending)
+    }
+
+    /// Predicate returns true for any row whose status on the
+    /// cluster is of the same kind as 'expected', Active matching
+    /// regardless of slot, when used in get_features filtering
+    pub fn status_on(&self, cluster: &str, expected: ScfsStatus) -> ScfsResult<ScfsRowPredicate> {
+        let column = self.cluster_column(cluster)?;
+        Ok(Box::new(move |row: &ScfsRow| {
+            row.status()
+                .get(column)
+                .is_some_and(|s| std::mem::discriminant(s) == std::mem::discriminant(&expected))
+        }))
+    }
+
+    /// Predicate returns true when both 'p1' and 'p2' do
+    pub fn and(
+        p1: impl Fn(&ScfsRow) -> bool + 'static,
+        p2: impl Fn(&ScfsRow) -> bool + 'static,
+    ) -> ScfsRowPredicate {
+        Box::new(move |row: &ScfsRow| p1(row) && p2(row))
+    }
+
+    /// Predicate returns true when either 'p1' or 'p2' does
+    pub fn or(
+        p1: impl Fn(&ScfsRow) -> bool + 'static,
+        p2: impl Fn(&ScfsRow) -> bool + 'static,
+    ) -> ScfsRowPredicate {
+        Box::new(move |row: &ScfsRow| p1(row) || p2(row))
+    }
+
+    /// Predicate returns true when 'p' does not
+    pub fn not(p: impl Fn(&ScfsRow) -> bool + 'static) -> ScfsRowPredicate {
+        Box::new(move |row: &ScfsRow| !p(row))
+    }
+
+    /// Per cluster, the number of features that are (active, pending, inactive)
+    pub fn summary(&self) -> HashMap<String, (usize, usize, usize)> {
+        self.criteria
+            .clusters
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(column, cluster)| {
+                let counts = self.rows.iter().fold((0, 0, 0), |(a, p, i), row| {
+                    match row.status().get(column) {
+                        Some(ScfsStatus::Active(_)) => (a + 1, p, i),
+                        Some(ScfsStatus::Pending) => (a, p + 1, i),
+                        Some(ScfsStatus::Inactive) => (a, p, i + 1),
+                        None => (a, p, i),
+                    }
+                });
+                (cluster.clone(), counts)
+            })
+            .collect()
+    }
+
     /// Retrieve features with optional predicate that
     /// tests one or more cluster status results for inclusion
     pub fn get_features(&self, f: Option<&dyn Fn(&ScfsRow) -> bool>) -> ScfsResult<Vec<Pubkey>> {
@@ -394,146 +1576,3438 @@ impl ScfsMatrix {
             .map(|r| r.key().clone())
             .collect())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use solana_sdk::pubkey::Pubkey;
+    /// Retrieve features with the reason a predicate gives
+    /// for including them, rows yielding None are excluded
+    pub fn get_features_explained(
+        &self,
+        f: &dyn Fn(&ScfsRow) -> Option<String>,
+    ) -> Vec<(Pubkey, String)> {
+        self.get_result_rows()
+            .iter()
+            .filter_map(|r| f(r).map(|reason| (*r.key(), reason)))
+            .collect()
+    }
 
-    use crate::{
-        ScfsCriteria, ScfsMatrix, SCFS_CLUSTER_LIST, SCFS_DEVNET, SCFS_FEATURE_PKS, SCFS_LOCAL,
-        SCFS_TESTNET,
-    };
+    /// The slot each cluster was at when it was queried by run
+    pub fn get_current_slots(&self) -> &HashMap<String, Slot> {
+        &self.current_slots
+    }
 
-    #[tokio::test]
-    async fn full_empty_criteria_pass() {
-        let mut my_matrix = ScfsMatrix::new(None).unwrap();
-        if let Some(c) = &my_matrix.get_criteria().features {
-            assert_eq!(c.len(), SCFS_FEATURE_PKS.len());
+    /// How many slots ago each Active feature activated on the cluster,
+    /// relative to the cluster's slot when it was queried
+    pub fn activation_age(&self, cluster: &str) -> ScfsResult<HashMap<Pubkey, Slot>> {
+        let column = self
+            .criteria
+            .clusters
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .position(|c| c == cluster);
+        let (column, current_slot) = match (column, self.current_slots.get(cluster)) {
+            (Some(column), Some(current_slot)) => (column, *current_slot),
+            _ => return Err(ScfsError::ClusterNotQueriedError(cluster.to_string())),
+        };
+        Ok(self
+            .rows
+            .iter()
+            .filter_map(|row| match row.status().get(column) {
+                Some(ScfsStatus::Active(slot)) => {
+                    Some((*row.key(), current_slot.saturating_sub(*slot)))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Status column of a cluster of the criteria
+    fn cluster_column(&self, cluster: &str) -> ScfsResult<usize> {
+        self.criteria
+            .clusters
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .position(|c| c == cluster)
+            .ok_or_else(|| ScfsError::ClusterNotQueriedError(cluster.to_string()))
+    }
+
+    /// Features Active on 'reference' but not on 'target', in row order, i.e. those
+    /// to activate on 'target' for its feature set to match. Slots are ignored
+    pub fn reconcile(&self, target: &str, reference: &str) -> ScfsResult<Vec<Pubkey>> {
+        let (target, reference) = (
+            self.cluster_column(target)?,
+            self.cluster_column(reference)?,
+        );
+        Ok(self
+            .rows
+            .iter()
+            .filter(|row| {
+                matches!(row.status().get(reference), Some(ScfsStatus::Active(_)))
+                    && !matches!(row.status().get(target), Some(ScfsStatus::Active(_)))
+            })
+            .map(|row| *row.key())
+            .collect())
+    }
+
+    /// Features, in row order, whose status kind differs between 'cluster_a' and
+    /// 'cluster_b', with the status on each. Activation slots are per cluster so two
+    /// Active statuses are not considered divergent
+    pub fn diff(
+        &self,
+        cluster_a: &str,
+        cluster_b: &str,
+    ) -> ScfsResult<Vec<(Pubkey, ScfsStatus, ScfsStatus)>> {
+        let (a, b) = (
+            self.cluster_column(cluster_a)?,
+            self.cluster_column(cluster_b)?,
+        );
+        Ok(self
+            .rows
+            .iter()
+            .filter_map(|row| match (row.status().get(a), row.status().get(b)) {
+                (Some(sa), Some(sb))
+                    if std::mem::discriminant(sa) != std::mem::discriminant(sb) =>
+                {
+                    Some((*row.key(), sa.clone(), sb.clone()))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Per feature, in row order, a bitmask with bit i set when the feature is Active
+    /// on the i-th cluster of the criteria (the order of the status columns), e.g.
+    /// 0b101 for Active on the first and third. Clusters past the 32nd are not represented
+    pub fn activation_bitmasks(&self) -> Vec<(Pubkey, u32)> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let mask = row
+                    .status()
+                    .iter()
+                    .take(u32::BITS as usize)
+                    .enumerate()
+                    .filter(|(_, status)| matches!(status, ScfsStatus::Active(_)))
+                    .fold(0u32, |mask, (column, _)| mask | 1 << column);
+                (*row.key(), mask)
+            })
+            .collect()
+    }
+
+    /// Features Active on every one of 'required_clusters', in row order.
+    /// Each required cluster must be one of the criteria's clusters
+    pub fn ready_features(&self, required_clusters: &[String]) -> ScfsResult<Vec<Pubkey>> {
+        let columns = required_clusters
+            .iter()
+            .map(|required| self.cluster_column(required))
+            .collect::<ScfsResult<Vec<usize>>>()?;
+        Ok(self
+            .rows
+            .iter()
+            .filter(|row| {
+                columns
+                    .iter()
+                    .all(|c| matches!(row.status().get(*c), Some(ScfsStatus::Active(_))))
+            })
+            .map(|row| *row.key())
+            .collect())
+    }
+
+    /// Whether the cluster is the faux local cluster, as the criteria asks
+    /// or as run fell back to when the local cluster refused to connect
+    fn is_faux(&self, cluster: &str) -> bool {
+        cluster == SCFS_LOCAL.as_str() && (self.criteria.faux_local || self.local_refused)
+    }
+
+    /// Fetches the epoch schedule of each cluster of the criteria so
+    /// activation slots resolve to epochs. The faux local cluster
+    /// uses the default schedule
+    pub async fn resolve_epochs(&mut self) -> ScfsResult<()> {
+        for cluster in self.criteria.clusters.clone().unwrap_or_default() {
+            let schedule = if self.is_faux(&cluster) {
+                EpochSchedule::default()
+            } else {
+                match self
+                    .transport_for(&cluster)
+                    .get_epoch_schedule()
+                    .await
+                    .map_err(|e| ScfsError::RpcError {
+                        cluster: cluster.clone(),
+                        source: Box::new(e),
+                    }) {
+                    Err(e) if cluster == *SCFS_LOCAL && e.is_connection_failure() => {
+                        EpochSchedule::default()
+                    }
+                    schedule => schedule?,
+                }
+            };
+            self.epoch_schedules.insert(cluster, schedule);
         }
-        if let Some(c) = &my_matrix.get_criteria().clusters {
-            assert_eq!(c.len(), SCFS_CLUSTER_LIST.len());
+        Ok(())
+    }
+
+    /// The slots per (post warmup) epoch of the cluster, once
+    /// resolve_epochs has fetched its epoch schedule
+    pub fn slots_per_epoch(&self, cluster: &str) -> Option<u64> {
+        self.epoch_schedules
+            .get(cluster)
+            .map(|schedule| schedule.slots_per_epoch)
+    }
+
+    /// The epoch a feature activated in on the cluster, None when it is not
+    /// Active there. Requires the cluster's epoch schedule from resolve_epochs
+    pub fn activation_epoch(&self, key: &Pubkey, cluster: &str) -> ScfsResult<Option<Epoch>> {
+        let column = self.cluster_column(cluster)?;
+        let schedule = self
+            .epoch_schedules
+            .get(cluster)
+            .ok_or_else(|| ScfsError::EpochScheduleMissingError(cluster.to_string()))?;
+        Ok(
+            match self.get_row(key).and_then(|row| row.status().get(column)) {
+                Some(ScfsStatus::Active(slot)) => Some(schedule.get_epoch(*slot)),
+                _ => None,
+            },
+        )
+    }
+
+    /// The Active features of the cluster bucketed by the epoch they
+    /// activated in, in row order within each epoch. Requires the
+    /// cluster's epoch schedule from resolve_epochs
+    pub fn features_by_activation_epoch(
+        &self,
+        cluster: &str,
+    ) -> ScfsResult<BTreeMap<Epoch, Vec<Pubkey>>> {
+        let column = self.cluster_column(cluster)?;
+        let schedule = self
+            .epoch_schedules
+            .get(cluster)
+            .ok_or_else(|| ScfsError::EpochScheduleMissingError(cluster.to_string()))?;
+        let mut buckets = BTreeMap::<Epoch, Vec<Pubkey>>::new();
+        for row in &self.rows {
+            if let Some(ScfsStatus::Active(slot)) = row.status().get(column) {
+                buckets
+                    .entry(schedule.get_epoch(*slot))
+                    .or_default()
+                    .push(*row.key());
+            }
         }
-        assert!(my_matrix.run().await.is_ok());
-        for res_row in my_matrix.get_result_rows() {
-            println!(
-                "{} Local {:?} Dev {:?}",
-                res_row.feature_key, res_row.feature_status[0], res_row.feature_status[1]
-            );
+        Ok(buckets)
+    }
+
+    /// Per feature timeline of status, activation slot and activation
+    /// time (the block time of the activation slot) for each cluster.
+    /// Clusters where the feature is not Active, and the faux local
+    /// cluster, have no timing
+    pub async fn timeline(&self) -> ScfsResult<Vec<FeatureTimeline>> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let mut timelines = Vec::<FeatureTimeline>::new();
+        for row in &self.rows {
+            let mut entries = Vec::<ClusterTimelineEntry>::new();
+            for (cluster, status) in clusters.iter().zip(row.status()) {
+                let (activation_slot, activation_time) = match status {
+                    ScfsStatus::Active(slot) if !self.is_faux(cluster) => {
+                        let time = self
+                            .transport_for(cluster)
+                            .get_block_time(*slot)
+                            .await
+                            .map_err(|source| ScfsError::BlockTimeError {
+                                cluster: cluster.clone(),
+                                slot: *slot,
+                                source: Box::new(source),
+                            })?;
+                        (Some(*slot), Some(time))
+                    }
+                    ScfsStatus::Active(slot) => (Some(*slot), None),
+                    _ => (None, None),
+                };
+                entries.push(ClusterTimelineEntry {
+                    cluster: cluster.clone(),
+                    status: status.clone(),
+                    activation_slot,
+                    activation_time,
+                });
+            }
+            timelines.push(FeatureTimeline {
+                feature_key: *row.key(),
+                entries,
+            });
         }
+        Ok(timelines)
     }
 
-    #[tokio::test]
-    async fn test_localnet_pass() {
-        let mut cluster_vec = Vec::<String>::new();
-        cluster_vec.push(SCFS_LOCAL.to_string());
-        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(cluster_vec),
-            ..Default::default()
-        }))
-        .unwrap();
-        assert!(my_matrix.run().await.is_ok());
-        for res_row in my_matrix.get_result_rows() {
-            println!("{:?}", res_row)
+    /// Features active on every queried cluster but exactly one,
+    /// paired with the name of that lagging cluster
+    pub fn laggard_report(&self) -> Vec<(Pubkey, String)> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        if clusters.len() < 2 {
+            return Vec::new();
         }
+        self.rows
+            .iter()
+            .filter_map(|row| {
+                let mut lagging = row
+                    .status()
+                    .iter()
+                    .zip(clusters)
+                    .filter(|(status, _)| !matches!(status, ScfsStatus::Active(_)));
+                match (lagging.next(), lagging.next()) {
+                    (Some((_, cluster)), None) => Some((*row.key(), cluster.clone())),
+                    _ => None,
+                }
+            })
+            .collect()
     }
-    #[tokio::test]
-    async fn test_devnet_pass() {
-        let mut cluster_vec = Vec::<String>::new();
-        cluster_vec.push(SCFS_DEVNET.to_string());
-        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(cluster_vec),
-            ..Default::default()
-        }))
-        .unwrap();
-        assert!(my_matrix.run().await.is_ok());
-        for res_row in my_matrix.get_result_rows() {
-            println!("{:?}", res_row)
+
+    /// Features pending on at least one queried cluster,
+    /// paired with the names of those clusters
+    pub fn pending_report(&self) -> Vec<(Pubkey, Vec<String>)> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        self.rows
+            .iter()
+            .filter_map(|row| {
+                let pending: Vec<String> = row
+                    .status()
+                    .iter()
+                    .zip(clusters)
+                    .filter(|(status, _)| **status == ScfsStatus::Pending)
+                    .map(|(_, cluster)| cluster.clone())
+                    .collect();
+                (!pending.is_empty()).then(|| (*row.key(), pending))
+            })
+            .collect()
+    }
+
+    /// Plain text summary of the rollout: total features, the count active on
+    /// each cluster and, per cluster, the features lagging there (active on
+    /// every other cluster) or pending there, with their descriptions
+    pub fn rollout_report(&self) -> String {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let laggards = self.laggard_report();
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Feature rollout: {} features across {} clusters",
+            self.rows.len(),
+            clusters.len()
+        );
+        for (index, cluster) in clusters.iter().enumerate() {
+            let active = self
+                .rows
+                .iter()
+                .filter(|r| matches!(r.status().get(index), Some(ScfsStatus::Active(_))))
+                .count();
+            let _ = writeln!(out, "{}: {} of {} active", cluster, active, self.rows.len());
+            for row in &self.rows {
+                let state = if laggards.contains(&(*row.key(), cluster.clone())) {
+                    "lagging"
+                } else if row.status().get(index) == Some(&ScfsStatus::Pending) {
+                    "pending"
+                } else {
+                    continue;
+                };
+                let _ = writeln!(out, "  {} {} {}", state, row.key(), row.desc());
+            }
         }
+        out
     }
-    #[tokio::test]
-    async fn test_devnet_filter_inactive_pass() {
-        let mut cluster_vec = Vec::<String>::new();
-        cluster_vec.push(SCFS_DEVNET.to_string());
-        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(cluster_vec),
-            ..Default::default()
-        }))
-        .unwrap();
-        assert!(my_matrix.run().await.is_ok());
-        let inactives = my_matrix
-            .get_features(Some(&ScfsMatrix::any_inactive))
-            .unwrap();
-        assert_ne!(inactives.len(), 0);
-        for res_row in inactives {
-            println!("{:?}", res_row)
+
+    /// Render the matrix in the Prometheus text exposition format
+    /// with per cluster feature counts by status and a per feature
+    /// status gauge (0 = inactive, 1 = pending, 2 = active)
+    pub fn to_prometheus(&self) -> String {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let mut out = String::new();
+        for (name, help, wanted) in [
+            ("active", "activated", 2u8),
+            ("pending", "pending activation", 1u8),
+            ("inactive", "not activated", 0u8),
+        ] {
+            let _ = writeln!(
+                out,
+                "# HELP scfs_features_{name} Number of features {help} on the cluster"
+            );
+            let _ = writeln!(out, "# TYPE scfs_features_{name} gauge");
+            for (index, cluster) in clusters.iter().enumerate() {
+                let count = self
+                    .rows
+                    .iter()
+                    .filter(|r| r.status().get(index).map(prometheus_status_value) == Some(wanted))
+                    .count();
+                let _ = writeln!(out, "scfs_features_{name}{{cluster=\"{cluster}\"}} {count}");
+            }
+        }
+        let _ = writeln!(
+            out,
+            "# HELP scfs_feature_status Feature status on the cluster (0 = inactive, 1 = pending, 2 = active)"
+        );
+        let _ = writeln!(out, "# TYPE scfs_feature_status gauge");
+        for row in &self.rows {
+            for (cluster, status) in clusters.iter().zip(row.status()) {
+                let _ = writeln!(
+                    out,
+                    "scfs_feature_status{{cluster=\"{cluster}\",feature=\"{}\"}} {}",
+                    row.key(),
+                    prometheus_status_value(status)
+                );
+            }
         }
+        out
     }
 
-    #[tokio::test]
-    async fn test_devnet_testnet_filter_all_inactive_pass() {
-        let mut cluster_vec = Vec::<String>::new();
-        cluster_vec.push(SCFS_DEVNET.to_string());
-        cluster_vec.push(SCFS_TESTNET.to_string());
-        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(cluster_vec),
-            ..Default::default()
-        }))
-        .unwrap();
-        assert!(my_matrix.run().await.is_ok());
-        let inactives = my_matrix
-            .get_features(Some(&ScfsMatrix::all_inactive))
-            .unwrap();
-        assert_ne!(inactives.len(), 0);
-        for res_row in inactives {
-            println!("{:?}", res_row)
+    /// Renders one `<feature> <cluster> <status>` line per feature and cluster,
+    /// sorted by feature key then cluster, so snapshots of a matrix diff cleanly
+    pub fn to_snapshot_text(&self) -> String {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let mut lines = Vec::<(String, &String, String)>::new();
+        for row in &self.rows {
+            for (cluster, status) in clusters.iter().zip(row.status()) {
+                let status = match status {
+                    ScfsStatus::Inactive => "inactive".to_string(),
+                    ScfsStatus::Pending => "pending".to_string(),
+                    ScfsStatus::Active(slot) => format!("active {}", slot),
+                };
+                lines.push((row.key().to_string(), cluster, status));
+            }
         }
+        lines.sort();
+        let mut out = String::new();
+        for (feature, cluster, status) in lines {
+            let _ = writeln!(out, "{} {} {}", feature, cluster, status);
+        }
+        out
     }
-    #[tokio::test]
-    async fn test_devnet_testnet_filter_all_active_pass() {
-        let mut cluster_vec = Vec::<String>::new();
-        cluster_vec.push(SCFS_DEVNET.to_string());
-        cluster_vec.push(SCFS_TESTNET.to_string());
-        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(cluster_vec),
-            ..Default::default()
-        }))
-        .unwrap();
-        assert!(my_matrix.run().await.is_ok());
-        let inactives = my_matrix
-            .get_features(Some(&ScfsMatrix::all_active))
-            .unwrap();
-        assert_ne!(inactives.len(), 0);
-        for res_row in inactives {
-            println!("{:?}", res_row)
+
+    /// Renders the matrix as CSV, a header row as SCFS_HEADER_LIST with the
+    /// criteria clusters in place of the default ones (and no description
+    /// column when the criteria excludes descriptions) then one row per
+    /// feature of its key, status on each cluster and description
+    pub fn to_csv(&self) -> ScfsResult<String> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let described = self.criteria.include_description;
+        let mut header = vec![&*SCFS_FEATURE_ID];
+        header.extend(clusters);
+        if described {
+            header.push(&*SCFS_DESCRIPTION);
+        }
+        let mut out = String::new();
+        let header: Vec<String> = header.into_iter().map(|h| csv_escape(h)).collect();
+        let _ = writeln!(out, "{}", header.join(","));
+        for row in &self.rows {
+            let mut fields = vec![row.key().to_string()];
+            fields.extend(row.status().iter().map(|s| s.to_string()));
+            if described {
+                fields.push(csv_escape(row.desc()));
+            }
+            let _ = writeln!(out, "{}", fields.join(","));
         }
+        Ok(out)
     }
-    #[tokio::test]
-    async fn bad_features_fail() {
-        let mut base_criteria = ScfsCriteria::default();
-        base_criteria.features = None;
-        let my_matrix = ScfsMatrix::new(Some(base_criteria));
-        assert!(my_matrix.is_err());
-        println!("{:?}", my_matrix);
-        let mut base_criteria = ScfsCriteria::default();
-        let faux_pkey = Pubkey::default();
-        let mut faux_vec = Vec::<Pubkey>::new();
-        faux_vec.push(faux_pkey);
-        base_criteria.features = Some(faux_vec);
-        let my_matrix = ScfsMatrix::new(Some(base_criteria));
-        assert!(my_matrix.is_err());
-        println!("{:?}", my_matrix);
+
+    /// Renders a standalone HTML page with a `<table>` of the matrix: a header
+    /// row of clusters, one row per feature with its description (unless the
+    /// criteria excludes descriptions), and status
+    /// cells classed and colored green (active), amber (pending) or red (inactive)
+    pub fn to_html(&self) -> String {
+        self.to_html_with_styles(&ScfsStatusStyles::default())
     }
-    #[tokio::test]
-    async fn bad_clusters_fail() {
-        let faux_field = "funny_business".to_string();
-        let mut faux_vec = Vec::<String>::new();
-        faux_vec.push(faux_field);
+
+    /// Renders the matrix as to_html does with the status cell
+    /// symbols and background colors taken from 'styles'
+    pub fn to_html_with_styles(&self, styles: &ScfsStatusStyles) -> String {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let described = self.criteria.include_description;
+        let mut out = String::new();
+        let _ = writeln!(out, "<!DOCTYPE html>");
+        let _ = writeln!(out, "<html>");
+        let _ = writeln!(
+            out,
+            "<head><meta charset=\"utf-8\"><title>Solana cluster feature status</title></head>"
+        );
+        let _ = writeln!(out, "<body>");
+        let _ = writeln!(out, "<table>");
+        let _ = write!(out, "<tr><th>Feature</th>");
+        if described {
+            let _ = write!(out, "<th>Description</th>");
+        }
+        for cluster in clusters {
+            let _ = write!(out, "<th>{}</th>", html_escape(cluster));
+        }
+        let _ = writeln!(out, "</tr>");
+        for row in &self.rows {
+            let _ = write!(out, "<tr><td>{}</td>", row.key());
+            if described {
+                let _ = write!(out, "<td>{}</td>", html_escape(row.desc()));
+            }
+            for status in row.status() {
+                let style = styles.style_for(status);
+                let (class, text) = match status {
+                    ScfsStatus::Active(slot) => ("active", format!("{} {}", style.symbol, slot)),
+                    ScfsStatus::Pending => ("pending", style.symbol.clone()),
+                    ScfsStatus::Inactive => ("inactive", style.symbol.clone()),
+                };
+                let (color, text) = (html_escape(&style.color), html_escape(&text));
+                let _ = write!(
+                    out,
+                    "<td class=\"{class}\" style=\"background-color:{color};color:#ffffff\">{text}</td>"
+                );
+            }
+            let _ = writeln!(out, "</tr>");
+        }
+        let _ = writeln!(out, "</table>");
+        let _ = writeln!(out, "</body>");
+        let _ = writeln!(out, "</html>");
+        out
+    }
+
+    /// The clusters, current slots and rows of the matrix in serializable form
+    pub fn to_projection(&self) -> ScfsMatrixProjection {
+        ScfsMatrixProjection {
+            clusters: self.criteria.clusters.clone().unwrap_or_default(),
+            current_slots: self.current_slots.clone(),
+            incomplete: self.incomplete,
+            rows: self.rows.clone(),
+        }
+    }
+
+    /// One flat JSON object per feature, its key as "feature", its description
+    /// as "name" (unless the criteria excludes descriptions) and its status
+    /// keyed by each cluster, e.g. `{"feature": .., "name": .., "devnet": "active"}`
+    pub fn to_records_json(&self) -> serde_json::Value {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let records: Vec<_> = self
+            .rows
+            .iter()
+            .map(|r| {
+                let mut record = json!({ "feature": r.key().to_string() });
+                if self.criteria.include_description {
+                    record["name"] = json!(r.desc());
+                }
+                for (cluster, status) in clusters.iter().zip(r.status()) {
+                    record[cluster] = json!(match status {
+                        ScfsStatus::Inactive => "inactive",
+                        ScfsStatus::Pending => "pending",
+                        ScfsStatus::Active(_) => "active",
+                    });
+                }
+                record
+            })
+            .collect();
+        json!(records)
+    }
+
+    /// A JSON document keyed by feature key, each feature's description (unless
+    /// the criteria excludes descriptions) and status keyed by each cluster, pretty
+    /// printed or compact:
+    ///
+    /// ```json
+    /// {
+    ///   "<feature key>": {
+    ///     "description": "secp256k1 program",
+    ///     "clusters": {
+    ///       "devnet": { "status": "active", "slot": 12345 },
+    ///       "mainnet": { "status": "pending" }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    pub fn to_json(&self, pretty: bool) -> ScfsResult<String> {
+        let clusters = self.criteria.clusters.as_deref().unwrap_or_default();
+        let mut document = serde_json::Map::new();
+        for row in &self.rows {
+            let statuses: serde_json::Map<String, serde_json::Value> = clusters
+                .iter()
+                .zip(row.status())
+                .map(|(cluster, status)| (cluster.clone(), json!(status)))
+                .collect();
+            let mut feature = json!({ "clusters": statuses });
+            if self.criteria.include_description {
+                feature["description"] = json!(row.desc());
+            }
+            document.insert(row.key().to_string(), feature);
+        }
+        let document = serde_json::Value::Object(document);
+        if pretty {
+            serde_json::to_string_pretty(&document)
+        } else {
+            serde_json::to_string(&document)
+        }
+        .map_err(|source| ScfsError::JsonError { source })
+    }
+
+    /// Writes one `<cluster>.json` or `<cluster>.csv` file per queried cluster
+    /// into 'dir', creating it if missing, listing each feature's description
+    /// (unless the criteria excludes descriptions),
+    /// status and activation slot on that cluster
+    pub fn export_per_cluster(&self, dir: &Path, format: ExportFormat) -> ScfsResult<()> {
+        let export_error = |path: &Path, source| ScfsError::ExportError {
+            path: path.display().to_string(),
+            source,
+        };
+        fs::create_dir_all(dir).map_err(|e| export_error(dir, e))?;
+        for (index, cluster) in self.criteria.clusters.iter().flatten().enumerate() {
+            let statuses = self.rows.iter().filter_map(|r| {
+                r.status().get(index).map(|status| {
+                    let (name, slot) = match status {
+                        ScfsStatus::Inactive => ("inactive", None),
+                        ScfsStatus::Pending => ("pending", None),
+                        ScfsStatus::Active(slot) => ("active", Some(*slot)),
+                    };
+                    (r, name, slot)
+                })
+            });
+            let (extension, content) = match format {
+                ExportFormat::Json => {
+                    let features: Vec<_> = statuses
+                        .map(|(r, status, slot)| {
+                            let mut feature = json!({
+                                "feature": r.key().to_string(),
+                                "status": status,
+                                "activation_slot": slot,
+                            });
+                            if self.criteria.include_description {
+                                feature["description"] = json!(r.desc());
+                            }
+                            feature
+                        })
+                        .collect();
+                    ("json", serde_json::to_string_pretty(&features).unwrap())
+                }
+                ExportFormat::Csv => {
+                    let described = self.criteria.include_description;
+                    let mut out = String::from(if described {
+                        "feature,description,status,activation_slot\n"
+                    } else {
+                        "feature,status,activation_slot\n"
+                    });
+                    for (r, status, slot) in statuses {
+                        let _ = write!(out, "{},", r.key());
+                        if described {
+                            let _ = write!(out, "\"{}\",", r.desc().replace('"', "\"\""));
+                        }
+                        let _ = writeln!(
+                            out,
+                            "{},{}",
+                            status,
+                            slot.map(|s| s.to_string()).unwrap_or_default()
+                        );
+                    }
+                    ("csv", out)
+                }
+            };
+            let path = dir.join(format!("{}.{}", cluster, extension));
+            fs::write(&path, content).map_err(|e| export_error(&path, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Gauge value of a ScfsStatus for Prometheus export
+fn prometheus_status_value(status: &ScfsStatus) -> u8 {
+    match status {
+        ScfsStatus::Inactive => 0,
+        ScfsStatus::Pending => 1,
+        ScfsStatus::Active(_) => 2,
+    }
+}
+
+/// Quotes a CSV field containing a delimiter, quote or line break,
+/// doubling its quotes
+fn csv_escape(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Escapes the characters HTML treats as markup
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Calls 'request' until it succeeds, fails with an error that is not transient
+/// or the policy's max_attempts are made, returning the last error. Each wait is
+/// the policy's delay for the attempt less up to half of it at random, so clients
+/// rate limited together don't retry in step. Without a policy 'request' is made once.
+/// A request not answered within 'timeout', or timed out by the client, fails
+/// with ScfsError::Timeout. A local cluster that refuses connections is not
+/// retried, it is treated as the faux local cluster
+async fn retry_transient<T, F, Fut>(
+    cluster: &str,
+    retry: Option<RetryPolicy>,
+    timeout: Option<Duration>,
+    mut request: F,
+) -> ScfsResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ClientResult<T>>,
+{
+    let timed_out = || ScfsError::Timeout {
+        cluster: cluster.to_string(),
+    };
+    let mut attempt = 1;
+    loop {
+        let response = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request()).await,
+            None => Ok(request().await),
+        };
+        let result = match response {
+            Err(_) => Err(timed_out()),
+            Ok(Err(e)) if matches!(e.kind(), ClientErrorKind::Reqwest(r) if r.is_timeout()) => {
+                Err(timed_out())
+            }
+            Ok(response) => response.map_err(|e| ScfsError::RpcError {
+                cluster: cluster.to_string(),
+                source: Box::new(e),
+            }),
+        };
+        match result {
+            Err(e) if cluster == SCFS_LOCAL.as_str() && e.is_connection_failure() => return Err(e),
+            Err(e) if e.is_transient() && retry.is_some_and(|r| attempt < r.max_attempts) => {
+                let delay = retry.unwrap().delay_for(attempt);
+                tokio::time::sleep(delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Maximum number of matrices run_many builds and runs at once
+pub const SCFS_RUN_MANY_LIMIT: usize = 4;
+
+/// Builds and runs a ScfsMatrix for each criteria concurrently,
+/// bounded by SCFS_RUN_MANY_LIMIT, returning results in input order
+pub async fn run_many(criteria: Vec<ScfsCriteria>) -> Vec<ScfsResult<ScfsMatrix>> {
+    stream::iter(criteria)
+        .map(|c| async move {
+            let mut matrix = ScfsMatrix::new(Some(c))?;
+            matrix.run().await?;
+            Ok(matrix)
+        })
+        .buffered(SCFS_RUN_MANY_LIMIT)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeMap, HashMap},
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use gadgets_common::RetryPolicy;
+    use serde_json::json;
+    use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+    use solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::RpcClientConfig,
+        rpc_request::RpcRequest,
+        rpc_sender::{RpcSender, RpcTransportStats},
+    };
+    use solana_sdk::{
+        account::Account,
+        clock::{Slot, UnixTimestamp},
+        commitment_config::CommitmentConfig,
+        epoch_schedule::EpochSchedule,
+        feature::{self, Feature},
+        feature_set,
+        pubkey::Pubkey,
+    };
+
+    use crate::{
+        cluster_url_with, estimated_slot_time, run_many,
+        scfs_errors::{ScfsError, ScfsResult},
+        scfs_transport::ScfsTransport,
+        ClusterTimelineEntry, ExportFormat, FeatureTimeline, ScfsCriteria, ScfsMatrix,
+        ScfsMatrixProjection, ScfsRow, ScfsSnapshot, ScfsStatus, ScfsStatusStyle, ScfsStatusStyles,
+        SCFS_AMBIGUOUS_FEATURE_NAMES, SCFS_CLUSTER_LIST, SCFS_DESCRIPTION, SCFS_DEVNET,
+        SCFS_FEATURE_ID, SCFS_FEATURE_KEYS, SCFS_FEATURE_NAMES, SCFS_FEATURE_PKS, SCFS_LOCAL,
+        SCFS_MAINNET, SCFS_PUBLIC_CLUSTER_LIST, SCFS_TESTNET, SCFS_UNKNOWN_FEATURE,
+        SCFS_URL_LOOKUPS,
+    };
+
+    #[test]
+    fn resolved_urls_override_pass() {
+        // The override is passed in as the process environment is shared by parallel tests
+        let var =
+            |name: &str| (name == "SCFS_LOCAL_URL").then(|| "http://127.0.0.1:9899".to_string());
+        assert_eq!(
+            cluster_url_with(&SCFS_LOCAL, var).unwrap(),
+            "http://127.0.0.1:9899"
+        );
+        assert_eq!(
+            cluster_url_with(&SCFS_MAINNET, var).unwrap(),
+            SCFS_URL_LOOKUPS[&*SCFS_MAINNET]
+        );
+        // An injected client's url and a custom cluster's url override the lookups
+        let my_matrix = ScfsMatrix::new_with_clients(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+                clusters: Some(vec![SCFS_LOCAL.to_string(), "staging".to_string()]),
+                custom_clusters: HashMap::from([(
+                    "staging".to_string(),
+                    "http://127.0.0.1:9900".to_string(),
+                )]),
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_LOCAL.to_string(),
+                Arc::new(RpcClient::new("http://127.0.0.1:9899".to_string())),
+            )]),
+        )
+        .unwrap();
+        assert_eq!(
+            my_matrix.resolved_urls(),
+            HashMap::from([
+                (SCFS_LOCAL.to_string(), "http://127.0.0.1:9899".to_string()),
+                ("staging".to_string(), "http://127.0.0.1:9900".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn to_snapshot_text_pass() {
+        let clusters = [&*SCFS_DEVNET, &*SCFS_MAINNET];
+        let statuses = vec![
+            vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+            vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+        ];
+        let snapshot = faux_matrix(&clusters, statuses.clone()).to_snapshot_text();
+        assert_eq!(
+            snapshot,
+            faux_matrix(&clusters, statuses.clone()).to_snapshot_text()
+        );
+        assert_eq!(snapshot.lines().count(), 4);
+        let mut sorted: Vec<&str> = snapshot.lines().collect();
+        sorted.sort();
+        assert_eq!(snapshot.lines().collect::<Vec<&str>>(), sorted);
+
+        let mut changed = statuses;
+        changed[0][1] = ScfsStatus::Active(30);
+        let changed = faux_matrix(&clusters, changed).to_snapshot_text();
+        let differing: Vec<(&str, &str)> = snapshot
+            .lines()
+            .zip(changed.lines())
+            .filter(|(a, b)| a != b)
+            .collect();
+        assert_eq!(
+            differing,
+            vec![(
+                format!("{} {} pending", SCFS_FEATURE_PKS[0], *SCFS_MAINNET).as_str(),
+                format!("{} {} active 30", SCFS_FEATURE_PKS[0], *SCFS_MAINNET).as_str()
+            )]
+        );
+    }
+
+    #[test]
+    fn to_html_pass() {
+        let clusters = [&*SCFS_DEVNET, &*SCFS_MAINNET];
+        let html = faux_matrix(
+            &clusters,
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+            ],
+        )
+        .to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!(html.matches("<table>").count(), 1);
+        assert_eq!(html.matches("<tr>").count(), 3);
+        assert_eq!(html.matches("<th>").count(), 4);
+        for key in &SCFS_FEATURE_PKS[..2] {
+            assert_eq!(html.matches(&format!("<td>{}</td>", key)).count(), 1);
+        }
+        assert_eq!(html.matches("class=\"active\"").count(), 2);
+        assert_eq!(html.matches("class=\"pending\"").count(), 1);
+        assert_eq!(html.matches("class=\"inactive\"").count(), 1);
+        assert!(html.contains(">active 20</td>"));
+    }
+
+    #[test]
+    fn to_html_with_styles_pass() {
+        let styles = ScfsStatusStyles {
+            pending: ScfsStatusStyle::new("waiting", "blue"),
+            inactive: ScfsStatusStyle::new("off", "grey"),
+            ..ScfsStatusStyles::default()
+        };
+        let mut my_matrix = faux_matrix(
+            &[&*SCFS_DEVNET, &*SCFS_MAINNET],
+            vec![vec![ScfsStatus::Pending, ScfsStatus::Inactive]],
+        );
+        let html = my_matrix.to_html_with_styles(&styles);
+        assert!(html.contains("<th>Description</th>"));
+        assert!(html.contains(
+            "<td class=\"pending\" style=\"background-color:blue;color:#ffffff\">waiting</td>"
+        ));
+        assert!(html.contains(
+            "<td class=\"inactive\" style=\"background-color:grey;color:#ffffff\">off</td>"
+        ));
+        my_matrix.criteria.include_description = false;
+        let html = my_matrix.to_html_with_styles(&styles);
+        assert!(!html.contains("<th>Description</th>"));
+        assert!(html.contains(&format!(
+            "<tr><td>{}</td><td class=\"pending\"",
+            my_matrix.rows[0].key()
+        )));
+    }
+
+    #[test]
+    fn diff_against_pass() {
+        let clusters = [&*SCFS_DEVNET, &*SCFS_MAINNET];
+        let previous = faux_matrix(
+            &clusters,
+            vec![
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+                vec![ScfsStatus::Active(20), ScfsStatus::Pending],
+            ],
+        )
+        .snapshot();
+        let current = faux_matrix(
+            &clusters,
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Inactive],
+                vec![ScfsStatus::Active(20), ScfsStatus::Active(30)],
+            ],
+        );
+        assert_eq!(
+            current.diff_against(&previous),
+            vec![
+                (
+                    SCFS_FEATURE_PKS[0],
+                    SCFS_DEVNET.to_string(),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(10)
+                ),
+                (
+                    SCFS_FEATURE_PKS[1],
+                    SCFS_MAINNET.to_string(),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(30)
+                ),
+            ]
+        );
+        assert!(current.diff_against(&current.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn regressions_since_pass() {
+        let clusters = [&*SCFS_DEVNET, &*SCFS_MAINNET];
+        let prior = faux_matrix(
+            &clusters,
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Active(15)],
+                vec![ScfsStatus::Active(20), ScfsStatus::Pending],
+            ],
+        )
+        .snapshot();
+        let current = faux_matrix(
+            &clusters,
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Inactive],
+                vec![ScfsStatus::Pending, ScfsStatus::Active(30)],
+            ],
+        );
+        assert_eq!(
+            current.regressions_since(&prior),
+            vec![
+                (SCFS_FEATURE_PKS[0], SCFS_MAINNET.to_string()),
+                (SCFS_FEATURE_PKS[1], SCFS_DEVNET.to_string()),
+            ]
+        );
+        assert!(current.regressions_since(&current.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn check_ordering_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Active(20), ScfsStatus::Active(30)],
+            ],
+        );
+        assert_eq!(
+            my_matrix.check_ordering(SCFS_FEATURE_PKS[0], SCFS_FEATURE_PKS[1]),
+            vec![SCFS_MAINNET.to_string()]
+        );
+        assert!(my_matrix
+            .check_ordering(SCFS_FEATURE_PKS[1], SCFS_FEATURE_PKS[0])
+            .is_empty());
+    }
+
+    #[test]
+    fn unqueried_clusters_pass() {
         let my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
-            clusters: Some(faux_vec),
+            clusters: Some(vec![SCFS_DEVNET.to_string()]),
             ..Default::default()
-        }));
-        assert!(my_matrix.is_err());
-        println!("{:?}", my_matrix);
+        }))
+        .unwrap();
+        assert_eq!(
+            my_matrix.unqueried_clusters(),
+            vec![
+                SCFS_LOCAL.to_string(),
+                SCFS_TESTNET.to_string(),
+                SCFS_MAINNET.to_string()
+            ]
+        );
+        assert!(ScfsMatrix::new(None)
+            .unwrap()
+            .unqueried_clusters()
+            .is_empty());
+    }
+
+    #[test]
+    fn default_row_order_stable_pass() {
+        let first = ScfsMatrix::new(None).unwrap();
+        let second = ScfsMatrix::new(None).unwrap();
+        let first_keys: Vec<_> = first
+            .get_result_rows()
+            .iter()
+            .map(|r| r.feature_key)
+            .collect();
+        let second_keys: Vec<_> = second
+            .get_result_rows()
+            .iter()
+            .map(|r| r.feature_key)
+            .collect();
+        assert_eq!(first_keys, second_keys);
+        assert!(first_keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn to_csv_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET, &SCFS_MAINNET],
+            vec![
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Active(9),
+                ],
+            ],
+        );
+        my_matrix.rows[1].feature_description = "quoted \"cap\", with commas".to_string();
+        let out = my_matrix.to_csv().unwrap();
+        let mut reader = csv::Reader::from_reader(out.as_bytes());
+        let header: Vec<String> = reader.headers().unwrap().iter().map(String::from).collect();
+        assert_eq!(
+            header,
+            vec![
+                SCFS_FEATURE_ID.to_string(),
+                SCFS_DEVNET.to_string(),
+                SCFS_TESTNET.to_string(),
+                SCFS_MAINNET.to_string(),
+                SCFS_DESCRIPTION.to_string(),
+            ]
+        );
+        let records: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(records.len(), 2);
+        for record in &records {
+            assert_eq!(
+                record.len(),
+                my_matrix.get_criteria().clusters.as_ref().unwrap().len() + 2
+            );
+        }
+        assert_eq!(
+            records[0].iter().collect::<Vec<_>>(),
+            vec![
+                SCFS_FEATURE_PKS[0].to_string().as_str(),
+                "active @ 1",
+                "pending",
+                "inactive",
+                SCFS_FEATURE_NAMES[&SCFS_FEATURE_PKS[0]].as_str(),
+            ]
+        );
+        assert_eq!(&records[1][4], "quoted \"cap\", with commas");
+
+        my_matrix.criteria.include_description = false;
+        let out = my_matrix.to_csv().unwrap();
+        let mut reader = csv::Reader::from_reader(out.as_bytes());
+        assert_eq!(reader.headers().unwrap().len(), 4);
+        assert!(reader.records().all(|r| r.unwrap().len() == 4));
+    }
+
+    #[test]
+    fn to_json_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(12), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+            ],
+        );
+        let pretty = my_matrix.to_json(true).unwrap();
+        let compact = my_matrix.to_json(false).unwrap();
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        let document: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        assert_eq!(
+            document,
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap()
+        );
+        assert_eq!(document.as_object().unwrap().len(), 2);
+        let feature = &document[SCFS_FEATURE_PKS[0].to_string()];
+        assert_eq!(
+            feature["description"],
+            json!(SCFS_FEATURE_NAMES[&SCFS_FEATURE_PKS[0]])
+        );
+        assert_eq!(
+            feature["clusters"][&*SCFS_DEVNET],
+            json!({"status": "active", "slot": 12})
+        );
+        assert_eq!(
+            feature["clusters"][&*SCFS_MAINNET],
+            json!({"status": "pending"})
+        );
+        assert_eq!(
+            document[SCFS_FEATURE_PKS[1].to_string()]["clusters"][&*SCFS_MAINNET],
+            json!({"status": "inactive"})
+        );
+
+        my_matrix.criteria.include_description = false;
+        let document: serde_json::Value =
+            serde_json::from_str(&my_matrix.to_json(false).unwrap()).unwrap();
+        assert!(document[SCFS_FEATURE_PKS[0].to_string()]
+            .get("description")
+            .is_none());
+    }
+
+    #[test]
+    fn export_per_cluster_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+            ],
+        );
+        let dir = std::env::temp_dir().join(format!("scfs_export_{}", std::process::id()));
+        my_matrix
+            .export_per_cluster(&dir, ExportFormat::Json)
+            .unwrap();
+        my_matrix
+            .export_per_cluster(&dir, ExportFormat::Csv)
+            .unwrap();
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec!["devnet.csv", "devnet.json", "mainnet.csv", "mainnet.json"]
+        );
+        let rows = my_matrix.get_result_rows();
+        let devnet: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("devnet.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            devnet,
+            json!([
+                {
+                    "feature": rows[0].key().to_string(),
+                    "description": rows[0].desc(),
+                    "status": "active",
+                    "activation_slot": 10
+                },
+                {
+                    "feature": rows[1].key().to_string(),
+                    "description": rows[1].desc(),
+                    "status": "inactive",
+                    "activation_slot": null
+                }
+            ])
+        );
+        let mainnet = std::fs::read_to_string(dir.join("mainnet.csv")).unwrap();
+        let lines: Vec<_> = mainnet.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "feature,description,status,activation_slot");
+        assert!(lines[1].starts_with(&rows[0].key().to_string()));
+        assert!(lines[1].ends_with(",pending,"));
+        assert!(lines[2].ends_with(",active,20"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn to_records_json_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+            ],
+        );
+        let rows = my_matrix.get_result_rows();
+        let records = my_matrix.to_records_json();
+        assert_eq!(
+            records,
+            json!([
+                {
+                    "feature": rows[0].key().to_string(),
+                    "name": rows[0].desc(),
+                    "devnet": "active",
+                    "mainnet": "pending"
+                },
+                {
+                    "feature": rows[1].key().to_string(),
+                    "name": rows[1].desc(),
+                    "devnet": "inactive",
+                    "mainnet": "active"
+                }
+            ])
+        );
+        let mut keys: Vec<_> = records[0].as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["devnet", "feature", "mainnet", "name"]);
+    }
+
+    #[test]
+    fn get_status_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+            ],
+        );
+        let key = SCFS_FEATURE_PKS[1];
+        assert_eq!(my_matrix.get_row(&key).unwrap().key(), &key);
+        assert_eq!(
+            my_matrix.get_status(&key, &SCFS_MAINNET),
+            Some(&ScfsStatus::Active(20))
+        );
+        assert_eq!(
+            my_matrix.get_status(&SCFS_FEATURE_PKS[0], &SCFS_MAINNET),
+            Some(&ScfsStatus::Pending)
+        );
+        assert!(my_matrix.get_status(&key, &SCFS_TESTNET).is_none());
+        assert!(my_matrix.get_row(&SCFS_FEATURE_PKS[2]).is_none());
+        assert!(my_matrix
+            .get_status(&SCFS_FEATURE_PKS[2], &SCFS_MAINNET)
+            .is_none());
+    }
+
+    #[test]
+    fn activation_bitmasks_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_LOCAL, &SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(3),
+                ],
+                vec![
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Pending,
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Active(3),
+                ],
+            ],
+        );
+        let rows = my_matrix.get_result_rows();
+        assert_eq!(
+            my_matrix.activation_bitmasks(),
+            vec![
+                (*rows[0].key(), 0b101),
+                (*rows[1].key(), 0b010),
+                (*rows[2].key(), 0),
+                (*rows[3].key(), 0b111),
+            ]
+        );
+    }
+
+    #[test]
+    fn status_display_pass() {
+        assert_eq!(ScfsStatus::Inactive.to_string(), "inactive");
+        assert_eq!(ScfsStatus::Pending.to_string(), "pending");
+        assert_eq!(ScfsStatus::Active(12345).to_string(), "active @ 12345");
+    }
+
+    #[test]
+    fn serde_round_trip_pass() {
+        assert_eq!(
+            serde_json::to_value(ScfsStatus::Active(12345)).unwrap(),
+            json!({"status": "active", "slot": 12345})
+        );
+        assert_eq!(
+            serde_json::to_value(ScfsStatus::Pending).unwrap(),
+            json!({"status": "pending"})
+        );
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![vec![ScfsStatus::Active(10), ScfsStatus::Inactive]],
+        );
+        my_matrix.rows[0].raw_features = vec![
+            Some(Feature {
+                activated_at: Some(10),
+            }),
+            None,
+        ];
+        let row = &my_matrix.get_result_rows()[0];
+        let text = serde_json::to_string(row).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&text).unwrap()["feature"],
+            json!(row.key().to_string())
+        );
+        assert_eq!(&serde_json::from_str::<ScfsRow>(&text).unwrap(), row);
+        let projection = my_matrix.to_projection();
+        assert_eq!(projection.clusters, vec!["devnet", "mainnet"]);
+        let text = serde_json::to_string(&projection).unwrap();
+        let restored = serde_json::from_str::<ScfsMatrixProjection>(&text).unwrap();
+        assert_eq!(restored, projection);
+        assert_eq!(ScfsSnapshot::from(&restored), my_matrix.snapshot());
+    }
+
+    /// Builds a matrix over the first features with faux
+    /// per cluster statuses (one Vec per row) without querying
+    fn faux_matrix(clusters: &[&String], statuses: Vec<Vec<ScfsStatus>>) -> ScfsMatrix {
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..statuses.len()].to_vec()),
+            clusters: Some(clusters.iter().map(|c| c.to_string()).collect()),
+            ..Default::default()
+        }))
+        .unwrap();
+        for (row, status) in my_matrix.rows.iter_mut().zip(statuses) {
+            row.feature_status = status;
+        }
+        my_matrix
+    }
+
+    #[tokio::test]
+    async fn full_empty_criteria_pass() {
+        let mut my_matrix = ScfsMatrix::new(None).unwrap();
+        if let Some(c) = &my_matrix.get_criteria().features {
+            assert_eq!(c.len(), SCFS_FEATURE_PKS.len());
+        }
+        if let Some(c) = &my_matrix.get_criteria().clusters {
+            assert_eq!(c.len(), SCFS_CLUSTER_LIST.len());
+        }
+        assert!(my_matrix.run().await.is_ok());
+        for res_row in my_matrix.get_result_rows() {
+            println!(
+                "{} Local {:?} Dev {:?}",
+                res_row.feature_key, res_row.feature_status[0], res_row.feature_status[1]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_localnet_pass() {
+        let mut cluster_vec = Vec::<String>::new();
+        cluster_vec.push(SCFS_LOCAL.to_string());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(cluster_vec),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+        for res_row in my_matrix.get_result_rows() {
+            println!("{:?}", res_row)
+        }
+    }
+    #[tokio::test]
+    async fn test_devnet_pass() {
+        let mut cluster_vec = Vec::<String>::new();
+        cluster_vec.push(SCFS_DEVNET.to_string());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(cluster_vec),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+        for res_row in my_matrix.get_result_rows() {
+            println!("{:?}", res_row)
+        }
+    }
+    #[tokio::test]
+    async fn test_devnet_filter_inactive_pass() {
+        let mut cluster_vec = Vec::<String>::new();
+        cluster_vec.push(SCFS_DEVNET.to_string());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(cluster_vec),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+        let inactives = my_matrix
+            .get_features(Some(&ScfsMatrix::any_inactive))
+            .unwrap();
+        assert_ne!(inactives.len(), 0);
+        for res_row in inactives {
+            println!("{:?}", res_row)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_devnet_testnet_filter_all_inactive_pass() {
+        let mut cluster_vec = Vec::<String>::new();
+        cluster_vec.push(SCFS_DEVNET.to_string());
+        cluster_vec.push(SCFS_TESTNET.to_string());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(cluster_vec),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+        let inactives = my_matrix
+            .get_features(Some(&ScfsMatrix::all_inactive))
+            .unwrap();
+        assert_ne!(inactives.len(), 0);
+        for res_row in inactives {
+            println!("{:?}", res_row)
+        }
+    }
+    #[tokio::test]
+    async fn test_devnet_testnet_filter_all_active_pass() {
+        let mut cluster_vec = Vec::<String>::new();
+        cluster_vec.push(SCFS_DEVNET.to_string());
+        cluster_vec.push(SCFS_TESTNET.to_string());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(cluster_vec),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+        let inactives = my_matrix
+            .get_features(Some(&ScfsMatrix::all_active))
+            .unwrap();
+        assert_ne!(inactives.len(), 0);
+        for res_row in inactives {
+            println!("{:?}", res_row)
+        }
+    }
+    #[tokio::test]
+    async fn bad_features_fail() {
+        let mut base_criteria = ScfsCriteria::default();
+        base_criteria.features = None;
+        let my_matrix = ScfsMatrix::new(Some(base_criteria));
+        assert!(my_matrix.is_err());
+        println!("{:?}", my_matrix);
+        let mut base_criteria = ScfsCriteria::default();
+        let faux_pkey = Pubkey::default();
+        let mut faux_vec = Vec::<Pubkey>::new();
+        faux_vec.push(faux_pkey);
+        base_criteria.features = Some(faux_vec);
+        let my_matrix = ScfsMatrix::new(Some(base_criteria));
+        assert!(my_matrix.is_err());
+        println!("{:?}", my_matrix);
+    }
+    #[test]
+    fn unknown_features_pass() {
+        let unknown = Pubkey::new_unique();
+        let criteria = ScfsCriteria {
+            features: Some(vec![SCFS_FEATURE_PKS[0], unknown]),
+            clusters: Some(vec![SCFS_DEVNET.to_string()]),
+            allow_unknown_features: true,
+            ..Default::default()
+        };
+        let my_matrix = ScfsMatrix::new(Some(criteria.clone())).unwrap();
+        assert_eq!(my_matrix.unknown_features(), &[unknown]);
+        assert_eq!(
+            my_matrix.get_row(&unknown).unwrap().desc(),
+            &*SCFS_UNKNOWN_FEATURE
+        );
+        assert_eq!(
+            my_matrix.get_row(&SCFS_FEATURE_PKS[0]).unwrap().desc(),
+            &SCFS_FEATURE_NAMES[&SCFS_FEATURE_PKS[0]]
+        );
+        assert!(ScfsMatrix::new(Some(ScfsCriteria {
+            allow_unknown_features: false,
+            ..criteria
+        }))
+        .is_err());
+        assert!(ScfsMatrix::new(None).unwrap().unknown_features().is_empty());
+    }
+
+    #[test]
+    fn duplicate_features_pass() {
+        let pks = &SCFS_FEATURE_PKS;
+        let my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(vec![pks[2], pks[0], pks[2], pks[1], pks[0], pks[2]]),
+            ..Default::default()
+        }))
+        .unwrap();
+        let keys: Vec<Pubkey> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| *r.key())
+            .collect();
+        assert_eq!(keys, vec![pks[2], pks[0], pks[1]]);
+        assert_eq!(my_matrix.get_query_set(), &keys);
+        assert_eq!(my_matrix.duplicate_features(), &[pks[2], pks[0], pks[2]]);
+        assert!(my_matrix.duplicate_clusters().is_empty());
+    }
+
+    #[test]
+    fn duplicate_clusters_pass() {
+        let my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(vec![
+                SCFS_MAINNET.to_string(),
+                SCFS_DEVNET.to_string(),
+                SCFS_MAINNET.to_string(),
+            ]),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert_eq!(
+            my_matrix.get_criteria().clusters,
+            Some(vec![SCFS_MAINNET.to_string(), SCFS_DEVNET.to_string()])
+        );
+        assert_eq!(my_matrix.duplicate_clusters(), &[SCFS_MAINNET.to_string()]);
+        assert!(my_matrix.duplicate_features().is_empty());
+        assert!(ScfsMatrix::new(None)
+            .unwrap()
+            .duplicate_clusters()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn bad_clusters_fail() {
+        let faux_field = "funny_business".to_string();
+        let mut faux_vec = Vec::<String>::new();
+        faux_vec.push(faux_field);
+        let my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(faux_vec),
+            ..Default::default()
+        }));
+        assert!(my_matrix.is_err());
+        println!("{:?}", my_matrix);
+    }
+
+    #[test]
+    fn pending_somewhere_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+            ],
+        );
+        let pending = my_matrix
+            .get_features(Some(&ScfsMatrix::pending_somewhere))
+            .unwrap();
+        assert_eq!(pending, vec![SCFS_FEATURE_PKS[0]]);
+    }
+
+    #[test]
+    fn predicate_combinators_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(9)],
+                vec![ScfsStatus::Active(1), ScfsStatus::Active(9)],
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+            ],
+        );
+        let mainnet_active = || {
+            my_matrix
+                .status_on(&SCFS_MAINNET, ScfsStatus::Active(0))
+                .unwrap()
+        };
+        let devnet_inactive = || {
+            my_matrix
+                .status_on(&SCFS_DEVNET, ScfsStatus::Inactive)
+                .unwrap()
+        };
+        let both = ScfsMatrix::and(mainnet_active(), devnet_inactive());
+        assert_eq!(
+            my_matrix.get_features(Some(&both)).unwrap(),
+            vec![SCFS_FEATURE_PKS[0]]
+        );
+        let either = ScfsMatrix::or(mainnet_active(), devnet_inactive());
+        assert_eq!(
+            my_matrix.get_features(Some(&either)).unwrap(),
+            vec![
+                SCFS_FEATURE_PKS[0],
+                SCFS_FEATURE_PKS[1],
+                SCFS_FEATURE_PKS[3]
+            ]
+        );
+        let neither = ScfsMatrix::not(either);
+        assert_eq!(
+            my_matrix.get_features(Some(&neither)).unwrap(),
+            vec![SCFS_FEATURE_PKS[2]]
+        );
+        let pending_not_all_inactive = ScfsMatrix::and(
+            ScfsMatrix::pending_somewhere,
+            ScfsMatrix::not(ScfsMatrix::all_inactive),
+        );
+        assert_eq!(
+            my_matrix
+                .get_features(Some(&pending_not_all_inactive))
+                .unwrap(),
+            vec![SCFS_FEATURE_PKS[2]]
+        );
+        assert!(matches!(
+            my_matrix.status_on(&SCFS_TESTNET, ScfsStatus::Pending),
+            Err(ScfsError::ClusterNotQueriedError(c)) if c == *SCFS_TESTNET
+        ));
+    }
+
+    #[tokio::test]
+    async fn pending_predicates_pass() {
+        let pks = &SCFS_FEATURE_PKS[..4];
+        let transports: HashMap<String, Arc<dyn ScfsTransport>> = HashMap::from([(
+            SCFS_DEVNET.to_string(),
+            Arc::new(MockTransport::new(
+                1_000,
+                &[(pks[0], Some(10)), (pks[1], None), (pks[2], None)],
+                &[],
+            )) as Arc<dyn ScfsTransport>,
+        )]);
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                ..Default::default()
+            }),
+            transports,
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        let pending = vec![pks[1], pks[2]];
+        assert_eq!(
+            my_matrix
+                .get_features(Some(&ScfsMatrix::any_pending))
+                .unwrap(),
+            pending
+        );
+        assert_eq!(
+            my_matrix
+                .get_features(Some(&ScfsMatrix::all_pending))
+                .unwrap(),
+            pending
+        );
+        assert_eq!(
+            my_matrix.summary(),
+            HashMap::from([(SCFS_DEVNET.to_string(), (1, 2, 1))])
+        );
+    }
+
+    #[test]
+    fn summary_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Pending, ScfsStatus::Active(9)],
+                vec![ScfsStatus::Pending, ScfsStatus::Pending],
+                vec![ScfsStatus::Active(1), ScfsStatus::Inactive],
+            ],
+        );
+        assert_eq!(
+            my_matrix
+                .get_features(Some(&ScfsMatrix::all_pending))
+                .unwrap(),
+            vec![SCFS_FEATURE_PKS[1]]
+        );
+        let summary = my_matrix.summary();
+        assert_eq!(summary[SCFS_DEVNET.as_str()], (1, 2, 0));
+        assert_eq!(summary[SCFS_MAINNET.as_str()], (1, 1, 1));
+        for (active, pending, inactive) in summary.values() {
+            assert_eq!(
+                active + pending + inactive,
+                my_matrix.get_result_rows().len()
+            );
+        }
+    }
+
+    #[test]
+    fn set_cluster_column_pass() {
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+            clusters: Some(vec![SCFS_DEVNET.to_string(), SCFS_MAINNET.to_string()]),
+            ..Default::default()
+        }))
+        .unwrap();
+        my_matrix
+            .set_cluster_column(
+                &SCFS_MAINNET,
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+            )
+            .unwrap();
+        my_matrix
+            .set_cluster_column(
+                &SCFS_DEVNET,
+                vec![ScfsStatus::Active(5), ScfsStatus::Pending],
+            )
+            .unwrap();
+        my_matrix
+            .set_cluster_column(
+                &SCFS_MAINNET,
+                vec![ScfsStatus::Active(9), ScfsStatus::Inactive],
+            )
+            .unwrap();
+        let statuses: Vec<_> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| r.status().clone())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                vec![ScfsStatus::Active(5), ScfsStatus::Active(9)],
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+            ]
+        );
+        assert!(matches!(
+            my_matrix.set_cluster_column(&SCFS_TESTNET, vec![ScfsStatus::Pending; 2]),
+            Err(ScfsError::UnrecognizedCriteriaTypeError { .. })
+        ));
+        assert!(matches!(
+            my_matrix.set_cluster_column(&SCFS_DEVNET, vec![ScfsStatus::Pending]),
+            Err(ScfsError::ColumnLengthError {
+                expected: 2,
+                found: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn pending_report_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET, &SCFS_MAINNET],
+            vec![
+                vec![
+                    ScfsStatus::Active(10),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Active(10),
+                    ScfsStatus::Active(20),
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(20),
+                    ScfsStatus::Pending,
+                ],
+            ],
+        );
+        assert_eq!(
+            my_matrix.pending_report(),
+            vec![
+                (SCFS_FEATURE_PKS[0], vec![SCFS_TESTNET.to_string()]),
+                (
+                    SCFS_FEATURE_PKS[2],
+                    vec![SCFS_DEVNET.to_string(), SCFS_MAINNET.to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_many_pass() {
+        let local = Some(vec![SCFS_LOCAL.to_string()]);
+        let results = run_many(vec![
+            ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+                clusters: local.clone(),
+                ..Default::default()
+            },
+            ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[2..5].to_vec()),
+                clusters: local,
+                ..Default::default()
+            },
+        ])
+        .await;
+        assert_eq!(results.len(), 2);
+        let first = results[0].as_ref().unwrap();
+        let second = results[1].as_ref().unwrap();
+        assert_eq!(first.get_result_rows().len(), 2);
+        assert_eq!(second.get_result_rows().len(), 3);
+        assert_eq!(second.get_result_rows()[0].key(), &SCFS_FEATURE_PKS[2]);
+        for row in first
+            .get_result_rows()
+            .iter()
+            .chain(second.get_result_rows())
+        {
+            assert_eq!(row.status().len(), 1);
+        }
+    }
+
+    #[test]
+    fn to_prometheus_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![ScfsStatus::Active(5), ScfsStatus::Pending],
+                vec![ScfsStatus::Active(9), ScfsStatus::Inactive],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+            ],
+        );
+        let metrics = my_matrix.to_prometheus();
+        let lines: Vec<&str> = metrics.lines().collect();
+        for expected in [
+            "# TYPE scfs_features_active gauge",
+            "scfs_features_active{cluster=\"devnet\"} 2",
+            "scfs_features_active{cluster=\"testnet\"} 0",
+            "scfs_features_pending{cluster=\"devnet\"} 0",
+            "scfs_features_pending{cluster=\"testnet\"} 1",
+            "scfs_features_inactive{cluster=\"devnet\"} 1",
+            "scfs_features_inactive{cluster=\"testnet\"} 2",
+            "# TYPE scfs_feature_status gauge",
+        ] {
+            assert!(lines.contains(&expected), "missing {expected}");
+        }
+        let first = format!(
+            "scfs_feature_status{{cluster=\"testnet\",feature=\"{}\"}} 1",
+            SCFS_FEATURE_PKS[0]
+        );
+        assert!(lines.contains(&first.as_str()));
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|l| l.starts_with("scfs_feature_status{"))
+                .count(),
+            6
+        );
+    }
+
+    #[test]
+    fn features_from_reader_pass() {
+        let list = format!(
+            "# features to check\n{}\n\n  {}  # trailing comment\n",
+            SCFS_FEATURE_PKS[0], SCFS_FEATURE_PKS[1]
+        );
+        let features = ScfsCriteria::features_from_reader(list.as_bytes()).unwrap();
+        assert_eq!(features, SCFS_FEATURE_PKS[..2].to_vec());
+    }
+
+    #[test]
+    fn features_from_reader_fail() {
+        let list = format!("{}\n# comment\nnot-a-key\n", SCFS_FEATURE_PKS[0]);
+        match ScfsCriteria::features_from_reader(list.as_bytes()) {
+            Err(ScfsError::InvalidFeatureKeyError { line, key }) => {
+                assert_eq!(line, 3);
+                assert_eq!(key, "not-a-key");
+            }
+            other => panic!("expected InvalidFeatureKeyError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_pass() {
+        let (secp, other) = (SCFS_FEATURE_PKS[0], SCFS_FEATURE_PKS[1]);
+        let criteria = ScfsCriteria::parse(
+            &format!("{}, {},", secp, SCFS_FEATURE_NAMES[&other]),
+            "d,mainnet-beta, testnet",
+        )
+        .unwrap();
+        assert_eq!(criteria.features, Some(vec![secp, other]));
+        assert_eq!(
+            criteria.clusters,
+            Some(vec![
+                SCFS_DEVNET.to_string(),
+                SCFS_MAINNET.to_string(),
+                SCFS_TESTNET.to_string()
+            ])
+        );
+        assert!(ScfsMatrix::new(Some(criteria)).is_ok());
+        assert_eq!(
+            ScfsCriteria::parse("", " ").unwrap(),
+            ScfsCriteria::default()
+        );
+    }
+
+    #[test]
+    fn parse_fail() {
+        let valid = SCFS_FEATURE_PKS[0].to_string();
+        assert!(matches!(
+            ScfsCriteria::parse(&format!("{},no such feature", valid), "devnet"),
+            Err(ScfsError::UnrecognizedCriteriaTypeError { ctype: "feature", element })
+                if element == vec!["no such feature".to_string()]
+        ));
+        let stranger = Pubkey::new_unique().to_string();
+        assert!(matches!(
+            ScfsCriteria::parse(&format!("{},{}", valid, stranger), "devnet"),
+            Err(ScfsError::UnrecognizedCriteriaTypeError { ctype: "feature", element })
+                if element == vec![stranger.clone()]
+        ));
+        assert!(matches!(
+            ScfsCriteria::parse(&valid, "devnet,moon,mars"),
+            Err(ScfsError::UnrecognizedCriteriaTypeError { ctype: "cluster", element })
+                if element == vec!["moon".to_string()]
+        ));
+    }
+
+    #[test]
+    fn from_feature_names_pass() {
+        let criteria = ScfsCriteria::from_feature_names(&[
+            "transaction wide compute cap",
+            "secp256k1 program",
+        ])
+        .unwrap();
+        assert_eq!(
+            criteria.features,
+            Some(vec![
+                feature_set::tx_wide_compute_cap::id(),
+                feature_set::secp256k1_program_enabled::id(),
+            ])
+        );
+        assert_eq!(criteria.clusters, ScfsCriteria::default().clusters);
+    }
+
+    #[test]
+    fn criteria_builder_pass() {
+        let criteria = ScfsCriteria::builder()
+            .feature_names(&["transaction wide compute cap", "secp256k1 program"])
+            .clusters(&[&SCFS_DEVNET, &SCFS_TESTNET])
+            .commitment(CommitmentConfig::finalized())
+            .build()
+            .unwrap();
+        assert_eq!(
+            criteria.features,
+            Some(vec![
+                feature_set::tx_wide_compute_cap::id(),
+                feature_set::secp256k1_program_enabled::id(),
+            ])
+        );
+        assert_eq!(
+            criteria.clusters,
+            Some(vec![SCFS_DEVNET.to_string(), SCFS_TESTNET.to_string()])
+        );
+        assert_eq!(criteria.commitment, CommitmentConfig::finalized());
+        assert_eq!(criteria.batch_size, ScfsCriteria::default().batch_size);
+        let my_matrix = ScfsMatrix::new(Some(criteria)).unwrap();
+        assert_eq!(my_matrix.get_result_rows().len(), 2);
+
+        let criteria = ScfsCriteria::builder()
+            .features(&[SCFS_FEATURE_PKS[0]])
+            .feature_names(&["secp256k1 program"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            criteria.features,
+            Some(vec![
+                SCFS_FEATURE_PKS[0],
+                feature_set::secp256k1_program_enabled::id()
+            ])
+        );
+        assert_eq!(
+            ScfsCriteria::builder().build().unwrap(),
+            ScfsCriteria::default()
+        );
+    }
+
+    #[test]
+    fn criteria_builder_fail() {
+        assert!(matches!(
+            ScfsCriteria::builder()
+                .feature_names(&["secp256k1 progam"])
+                .build(),
+            Err(ScfsError::UnrecognizedCriteriaTypeError {
+                ctype: "feature",
+                ..
+            })
+        ));
+        assert!(matches!(
+            ScfsCriteria::builder().clusters(&["moonnet"]).build(),
+            Err(ScfsError::UnrecognizedCriteriaTypeError {
+                ctype: "cluster",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn from_feature_names_fail() {
+        assert!(matches!(
+            ScfsCriteria::from_feature_names(&["secp256k1 program", "secp256k1 progam"]),
+            Err(ScfsError::UnrecognizedCriteriaTypeError { ctype: "feature", element })
+                if element == vec!["secp256k1 progam".to_string()]
+        ));
+    }
+
+    #[test]
+    fn ambiguous_feature_name_fail() {
+        let shared = "fail libsecp256k1_verify if count appears wrong";
+        assert!(SCFS_AMBIGUOUS_FEATURE_NAMES.contains(shared));
+        assert!(!SCFS_FEATURE_KEYS.contains_key(shared));
+        let named = |result: ScfsResult<ScfsCriteria>| matches!(result, Err(ScfsError::AmbiguousFeatureNameError(names)) if names == vec![shared.to_string()]);
+        assert!(named(ScfsCriteria::from_feature_names(&[
+            "secp256k1 program",
+            shared
+        ])));
+        assert!(named(ScfsCriteria::parse(
+            &format!("secp256k1 program, {}", shared),
+            ""
+        )));
+        assert!(named(
+            ScfsCriteria::builder().feature_names(&[shared]).build()
+        ));
+    }
+
+    #[test]
+    fn from_config_file_pass() {
+        let name = SCFS_FEATURE_NAMES[&SCFS_FEATURE_PKS[0]].clone();
+        let path = std::env::temp_dir().join(format!("scfs_config_{}.yml", std::process::id()));
+        std::fs::write(
+            &path,
+            format!(
+                "features:\n  - {}\n  - \"{}\"\nclusters: [devnet, mainnet]\nbatch_size: 50\ntimeout: 30\n",
+                SCFS_FEATURE_PKS[1], name
+            ),
+        )
+        .unwrap();
+        let criteria = ScfsCriteria::from_config_file(&path).unwrap();
+        assert_eq!(
+            criteria,
+            ScfsCriteria {
+                features: Some(vec![SCFS_FEATURE_PKS[1], SCFS_FEATURE_PKS[0]]),
+                clusters: Some(vec![SCFS_DEVNET.to_string(), SCFS_MAINNET.to_string()]),
+                batch_size: 50,
+                timeout: Some(Duration::from_secs(30)),
+                ..Default::default()
+            }
+        );
+        assert!(ScfsMatrix::new(Some(criteria)).is_ok());
+
+        std::fs::write(&path, "clusters: [devnet, moonnet]\n").unwrap();
+        match ScfsCriteria::from_config_file(&path) {
+            Err(ScfsError::ConfigEntryError { entry, .. }) => {
+                assert_eq!(entry, "cluster 'moonnet'")
+            }
+            other => panic!("expected ConfigEntryError, got {:?}", other),
+        }
+        std::fs::write(&path, "features: [no such feature]\n").unwrap();
+        match ScfsCriteria::from_config_file(&path) {
+            Err(ScfsError::ConfigEntryError { entry, .. }) => {
+                assert_eq!(entry, "feature 'no such feature'")
+            }
+            other => panic!("expected ConfigEntryError, got {:?}", other),
+        }
+        let stranger = Pubkey::new_unique();
+        std::fs::write(&path, format!("features: [{}]\n", stranger)).unwrap();
+        match ScfsCriteria::from_config_file(&path) {
+            Err(ScfsError::ConfigEntryError { entry, .. }) => {
+                assert_eq!(entry, format!("feature '{}'", stranger))
+            }
+            other => panic!("expected ConfigEntryError, got {:?}", other),
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn raw_feature_pass() {
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+            clusters: Some(vec![SCFS_DEVNET.to_string(), SCFS_TESTNET.to_string()]),
+            keep_decoded: true,
+            ..Default::default()
+        }))
+        .unwrap();
+        let active = feature::create_account(
+            &Feature {
+                activated_at: Some(42),
+            },
+            1,
+        );
+        let pending = feature::create_account(&Feature { activated_at: None }, 1);
+        my_matrix.set_status_for_row(0, Some(active.into()));
+        my_matrix.set_status_for_row(1, None);
+        my_matrix.set_status_for_row(0, Some(pending.into()));
+        my_matrix.set_status_for_row(1, None);
+        let rows = my_matrix.get_result_rows();
+        assert_eq!(
+            rows[0].status(),
+            &vec![ScfsStatus::Active(42), ScfsStatus::Pending]
+        );
+        assert_eq!(rows[0].raw_feature(0).unwrap().activated_at, Some(42));
+        assert_eq!(rows[0].raw_feature(1).unwrap().activated_at, None);
+        assert!(rows[1].raw_feature(0).is_none());
+        assert!(rows[0].raw_feature(2).is_none());
+    }
+
+    #[test]
+    fn rollout_report_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(1), ScfsStatus::Inactive],
+                vec![ScfsStatus::Active(1), ScfsStatus::Active(2)],
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+            ],
+        );
+        let rows = my_matrix.get_result_rows();
+        let report = my_matrix.rollout_report();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Feature rollout: 3 features across 2 clusters".to_string(),
+                "devnet: 2 of 3 active".to_string(),
+                format!("  pending {} {}", rows[2].key(), rows[2].desc()),
+                "mainnet: 1 of 3 active".to_string(),
+                format!("  lagging {} {}", rows[0].key(), rows[0].desc()),
+            ]
+        );
+    }
+
+    #[test]
+    fn laggard_report_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET, &SCFS_MAINNET],
+            vec![
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Active(3),
+                ],
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Active(3),
+                ],
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Inactive,
+                ],
+                vec![
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Active(3),
+                ],
+            ],
+        );
+        assert_eq!(
+            my_matrix.laggard_report(),
+            vec![
+                (SCFS_FEATURE_PKS[0], SCFS_TESTNET.to_string()),
+                (SCFS_FEATURE_PKS[3], SCFS_DEVNET.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_features_explained_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![ScfsStatus::Active(1), ScfsStatus::Inactive],
+                vec![ScfsStatus::Active(1), ScfsStatus::Active(2)],
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+            ],
+        );
+        let clusters = my_matrix.get_criteria().clusters.clone().unwrap();
+        let inactive_on = |row: &ScfsRow| {
+            row.status()
+                .iter()
+                .position(|s| s == &ScfsStatus::Inactive)
+                .map(|i| format!("inactive on {}", clusters[i]))
+        };
+        assert_eq!(
+            my_matrix.get_features_explained(&inactive_on),
+            vec![
+                (SCFS_FEATURE_PKS[0], "inactive on testnet".to_string()),
+                (SCFS_FEATURE_PKS[2], "inactive on testnet".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![ScfsStatus::Active(1), ScfsStatus::Active(9)],
+                vec![ScfsStatus::Pending, ScfsStatus::Active(9)],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+                vec![ScfsStatus::Active(1), ScfsStatus::Inactive],
+                vec![ScfsStatus::Inactive, ScfsStatus::Pending],
+            ],
+        );
+        let divergent = my_matrix.diff(&SCFS_DEVNET, &SCFS_TESTNET).unwrap();
+        for (key, devnet, testnet) in &divergent {
+            println!("{key}: devnet {devnet}, testnet {testnet}");
+        }
+        assert_eq!(
+            divergent,
+            vec![
+                (
+                    SCFS_FEATURE_PKS[1],
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(9)
+                ),
+                (
+                    SCFS_FEATURE_PKS[3],
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Inactive
+                ),
+                (
+                    SCFS_FEATURE_PKS[4],
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Pending
+                ),
+            ]
+        );
+        assert!(my_matrix
+            .diff(&SCFS_TESTNET, &SCFS_TESTNET)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            my_matrix.diff(&SCFS_DEVNET, &SCFS_MAINNET),
+            Err(ScfsError::ClusterNotQueriedError(c)) if c == *SCFS_MAINNET
+        ));
+    }
+
+    #[test]
+    fn sort_by_name_pass() {
+        let mut my_matrix = faux_matrix(&[&SCFS_DEVNET], vec![vec![]; 8]);
+        my_matrix.sort_by_name();
+        let descriptions: Vec<&String> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| r.desc())
+            .collect();
+        let mut sorted = descriptions.clone();
+        sorted.sort();
+        assert_eq!(descriptions, sorted);
+        let keys: Vec<Pubkey> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| *r.key())
+            .collect();
+        assert_eq!(my_matrix.get_query_set(), &keys);
+    }
+
+    #[test]
+    fn sort_by_activation_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(1)],
+                vec![ScfsStatus::Active(30), ScfsStatus::Active(1)],
+                vec![ScfsStatus::Pending, ScfsStatus::Active(1)],
+                vec![ScfsStatus::Active(10), ScfsStatus::Inactive],
+            ],
+        );
+        my_matrix.sort_by_activation(&SCFS_DEVNET).unwrap();
+        let keys: Vec<Pubkey> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| *r.key())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                SCFS_FEATURE_PKS[3],
+                SCFS_FEATURE_PKS[1],
+                SCFS_FEATURE_PKS[2],
+                SCFS_FEATURE_PKS[0]
+            ]
+        );
+        assert_eq!(my_matrix.get_query_set(), &keys);
+        assert_eq!(
+            my_matrix.get_status(&SCFS_FEATURE_PKS[3], &SCFS_MAINNET),
+            Some(&ScfsStatus::Inactive)
+        );
+        assert!(matches!(
+            my_matrix.sort_by_activation(&SCFS_TESTNET),
+            Err(ScfsError::ClusterNotQueriedError(c)) if c == *SCFS_TESTNET
+        ));
+    }
+
+    #[test]
+    fn reconcile_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![
+                vec![ScfsStatus::Active(1), ScfsStatus::Active(9)],
+                vec![ScfsStatus::Pending, ScfsStatus::Active(9)],
+                vec![ScfsStatus::Inactive, ScfsStatus::Active(9)],
+                vec![ScfsStatus::Active(1), ScfsStatus::Inactive],
+                vec![ScfsStatus::Inactive, ScfsStatus::Pending],
+            ],
+        );
+        assert_eq!(
+            my_matrix.reconcile(&SCFS_DEVNET, &SCFS_MAINNET).unwrap(),
+            vec![SCFS_FEATURE_PKS[1], SCFS_FEATURE_PKS[2]]
+        );
+        assert_eq!(
+            my_matrix.reconcile(&SCFS_MAINNET, &SCFS_DEVNET).unwrap(),
+            vec![SCFS_FEATURE_PKS[3]]
+        );
+        assert!(matches!(
+            my_matrix.reconcile(&SCFS_TESTNET, &SCFS_MAINNET),
+            Err(ScfsError::ClusterNotQueriedError(c)) if c == *SCFS_TESTNET
+        ));
+    }
+
+    #[test]
+    fn ready_features_pass() {
+        let my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET, &SCFS_MAINNET],
+            vec![
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Active(3),
+                ],
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Pending,
+                ],
+                vec![
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Active(3),
+                ],
+                vec![
+                    ScfsStatus::Active(1),
+                    ScfsStatus::Active(2),
+                    ScfsStatus::Active(3),
+                ],
+            ],
+        );
+        let production = [SCFS_DEVNET.to_string(), SCFS_MAINNET.to_string()];
+        assert_eq!(
+            my_matrix.ready_features(&production).unwrap(),
+            vec![SCFS_FEATURE_PKS[0], SCFS_FEATURE_PKS[3]]
+        );
+        assert_eq!(
+            my_matrix.ready_features(&[]).unwrap(),
+            SCFS_FEATURE_PKS[..4].to_vec()
+        );
+        assert!(matches!(
+            my_matrix.ready_features(&[SCFS_DEVNET.to_string(), SCFS_LOCAL.to_string()]),
+            Err(ScfsError::ClusterNotQueriedError(c)) if c == *SCFS_LOCAL
+        ));
+    }
+
+    #[test]
+    fn fallback_description_pass() {
+        let key = Pubkey::new_unique();
+        assert_eq!(ScfsRow::new(key, String::new()).desc(), &key.to_string());
+        assert_eq!(
+            ScfsRow::new(key, "described".to_string()).desc(),
+            "described"
+        );
+    }
+
+    #[test]
+    fn activation_age_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![
+                vec![ScfsStatus::Active(100), ScfsStatus::Active(50)],
+                vec![ScfsStatus::Pending, ScfsStatus::Active(990)],
+                vec![ScfsStatus::Inactive, ScfsStatus::Inactive],
+            ],
+        );
+        assert!(my_matrix.activation_age(&SCFS_DEVNET).is_err());
+        my_matrix
+            .current_slots
+            .insert(SCFS_DEVNET.to_string(), 1_000);
+        my_matrix
+            .current_slots
+            .insert(SCFS_TESTNET.to_string(), 1_000);
+        let devnet = my_matrix.activation_age(&SCFS_DEVNET).unwrap();
+        assert_eq!(devnet, HashMap::from([(SCFS_FEATURE_PKS[0], 900)]));
+        let testnet = my_matrix.activation_age(&SCFS_TESTNET).unwrap();
+        assert_eq!(
+            testnet,
+            HashMap::from([(SCFS_FEATURE_PKS[0], 950), (SCFS_FEATURE_PKS[1], 10)])
+        );
+        assert!(my_matrix.activation_age(&SCFS_MAINNET).is_err());
+    }
+
+    #[tokio::test]
+    async fn new_with_clients_pass() {
+        // Feature { activated_at: Some(42) } serialized with bincode
+        let mock = RpcClient::new_mock_with_mocks(
+            "succeeds".to_string(),
+            HashMap::from([
+                (RpcRequest::GetSlot, json!(500)),
+                (
+                    RpcRequest::GetMultipleAccounts,
+                    json!({
+                        "context": {"slot": 500},
+                        "value": [{
+                            "lamports": 1,
+                            "data": ["ASoAAAAAAAAA", "base64"],
+                            "owner": feature::id().to_string(),
+                            "executable": false,
+                            "rentEpoch": 0,
+                            "space": 9
+                        }]
+                    }),
+                ),
+            ]),
+        );
+        let mut my_matrix = ScfsMatrix::new_with_clients(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                ..Default::default()
+            }),
+            HashMap::from([(SCFS_DEVNET.to_string(), Arc::new(mock))]),
+        )
+        .unwrap();
+        assert_eq!(
+            my_matrix.resolved_urls()[&*SCFS_DEVNET],
+            "MockSender: succeeds"
+        );
+        my_matrix.run().await.unwrap();
+        assert_eq!(my_matrix.get_current_slots()[&*SCFS_DEVNET], 500);
+        assert_eq!(
+            my_matrix.get_result_rows()[0].status(),
+            &vec![ScfsStatus::Active(42)]
+        );
+    }
+
+    /// Deterministic transport serving a fixed slot and accounts
+    struct MockTransport {
+        slot: Slot,
+        accounts: HashMap<Pubkey, Account>,
+        epoch_schedule: EpochSchedule,
+        delay: Duration,
+        failures: Mutex<Vec<ClientError>>,
+        account_requests: Mutex<usize>,
+        commitments: Mutex<Vec<CommitmentConfig>>,
+    }
+
+    impl MockTransport {
+        /// Serves a feature account per (key, activation) and a
+        /// non feature account for each of 'garbled'
+        fn new(slot: Slot, features: &[(Pubkey, Option<Slot>)], garbled: &[Pubkey]) -> Self {
+            let mut accounts = HashMap::<Pubkey, Account>::new();
+            for (key, activated_at) in features {
+                let feature = Feature {
+                    activated_at: *activated_at,
+                };
+                accounts.insert(*key, feature::create_account(&feature, 1).into());
+            }
+            for key in garbled {
+                accounts.insert(*key, Account::new(1, 0, &feature::id()));
+            }
+            Self {
+                slot,
+                accounts,
+                epoch_schedule: EpochSchedule::default(),
+                delay: Duration::ZERO,
+                failures: Mutex::new(vec![]),
+                account_requests: Mutex::new(0),
+                commitments: Mutex::new(vec![]),
+            }
+        }
+
+        /// Fails the first account requests, one with each of 'failures'
+        fn with_failures(self, mut failures: Vec<ClientError>) -> Self {
+            failures.reverse();
+            *self.failures.lock().unwrap() = failures;
+            self
+        }
+
+        /// Number of account requests made
+        fn account_requests(&self) -> usize {
+            *self.account_requests.lock().unwrap()
+        }
+
+        /// Delays each slot and accounts response by 'delay'
+        fn with_delay(mut self, delay: Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScfsTransport for MockTransport {
+        async fn get_slot(&self, commitment: CommitmentConfig) -> ClientResult<Slot> {
+            tokio::time::sleep(self.delay).await;
+            self.commitments.lock().unwrap().push(commitment);
+            Ok(self.slot)
+        }
+
+        async fn get_multiple_accounts(
+            &self,
+            keys: &[Pubkey],
+            commitment: CommitmentConfig,
+        ) -> ClientResult<Vec<Option<Account>>> {
+            tokio::time::sleep(self.delay).await;
+            self.commitments.lock().unwrap().push(commitment);
+            *self.account_requests.lock().unwrap() += 1;
+            if let Some(failure) = self.failures.lock().unwrap().pop() {
+                return Err(failure);
+            }
+            Ok(keys.iter().map(|k| self.accounts.get(k).cloned()).collect())
+        }
+
+        async fn get_epoch_schedule(&self) -> ClientResult<EpochSchedule> {
+            Ok(self.epoch_schedule.clone())
+        }
+
+        async fn get_block_time(&self, slot: Slot) -> ClientResult<UnixTimestamp> {
+            Ok(1_600_000_000 + slot as UnixTimestamp)
+        }
+    }
+
+    #[tokio::test]
+    async fn commitment_pass() {
+        for commitment in [
+            CommitmentConfig::confirmed(),
+            CommitmentConfig::processed(),
+            CommitmentConfig::finalized(),
+        ] {
+            let devnet = Arc::new(MockTransport::new(1_000, &[], &[]));
+            let mut my_matrix = ScfsMatrix::new_with_transports(
+                Some(ScfsCriteria {
+                    features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+                    clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                    commitment,
+                    ..Default::default()
+                }),
+                HashMap::from([(
+                    SCFS_DEVNET.to_string(),
+                    devnet.clone() as Arc<dyn ScfsTransport>,
+                )]),
+            )
+            .unwrap();
+            my_matrix.run().await.unwrap();
+            assert_eq!(*devnet.commitments.lock().unwrap(), vec![commitment; 2]);
+        }
+        assert_eq!(
+            ScfsCriteria::default().commitment,
+            CommitmentConfig::confirmed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_devnet_finalized_pass() {
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            clusters: Some(vec![SCFS_DEVNET.to_string()]),
+            commitment: CommitmentConfig::finalized(),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(my_matrix.run().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unreachable_cluster_fail() {
+        let mut my_matrix = ScfsMatrix::new_with_clients(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+                clusters: Some(vec![SCFS_LOCAL.to_string(), SCFS_DEVNET.to_string()]),
+                faux_local: true,
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_DEVNET.to_string(),
+                Arc::new(RpcClient::new("http://127.0.0.1:1".to_string())),
+            )]),
+        )
+        .unwrap();
+        assert!(matches!(
+            my_matrix.run().await,
+            Err(ScfsError::RpcError { cluster, .. }) if cluster == *SCFS_DEVNET
+        ));
+        assert!(my_matrix.is_incomplete());
+        // Only the local column, completed before devnet failed
+        for row in my_matrix.get_result_rows() {
+            assert_eq!(row.status(), &vec![ScfsStatus::Active(0)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn local_cluster_pass() {
+        let pks = &SCFS_FEATURE_PKS[..2];
+        let criteria = ScfsCriteria {
+            features: Some(pks.to_vec()),
+            clusters: Some(vec![SCFS_LOCAL.to_string()]),
+            ..Default::default()
+        };
+        // The local validator is queried like any other cluster
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(criteria.clone()),
+            HashMap::from([(
+                SCFS_LOCAL.to_string(),
+                Arc::new(MockTransport::new(50, &[(pks[0], Some(5))], &[]))
+                    as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        let statuses: Vec<_> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| r.status().clone())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![vec![ScfsStatus::Active(5)], vec![ScfsStatus::Inactive]]
+        );
+        assert_eq!(my_matrix.get_current_slots()[&*SCFS_LOCAL], 50);
+        // Without a validator to connect to every feature is Active(0)
+        let mut my_matrix = ScfsMatrix::new_with_clients(
+            Some(criteria),
+            HashMap::from([(
+                SCFS_LOCAL.to_string(),
+                Arc::new(RpcClient::new("http://127.0.0.1:1".to_string())),
+            )]),
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        assert!(!my_matrix.is_incomplete());
+        for row in my_matrix.get_result_rows() {
+            assert_eq!(row.status(), &vec![ScfsStatus::Active(0)]);
+        }
+        assert!(my_matrix
+            .validate_features_on_cluster(&SCFS_LOCAL)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn local_cluster_refused_pass() {
+        let refused = || {
+            ClientError::from(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                "connection refused",
+            ))
+        };
+        let local = Arc::new(MockTransport::new(50, &[], &[]).with_failures(vec![refused()]));
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+                clusters: Some(vec![SCFS_LOCAL.to_string()]),
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_LOCAL.to_string(),
+                local.clone() as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        // The refused connection falls back without retrying
+        assert_eq!(local.account_requests(), 1);
+        assert_eq!(
+            my_matrix.get_result_rows()[0].status(),
+            &vec![ScfsStatus::Active(0)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn transient_retry_pass() {
+        let pks = &SCFS_FEATURE_PKS[..1];
+        let transient = || {
+            ClientError::from(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "connection reset",
+            ))
+        };
+        let permanent = || ClientError::from(ClientErrorKind::Custom("bad request".to_string()));
+        assert!(ScfsError::RpcError {
+            cluster: SCFS_DEVNET.to_string(),
+            source: Box::new(transient())
+        }
+        .is_transient());
+        assert!(!ScfsError::ClusterNotQueriedError(SCFS_DEVNET.to_string()).is_transient());
+        assert!(ScfsError::Timeout {
+            cluster: SCFS_DEVNET.to_string()
+        }
+        .is_transient());
+        let devnet = Arc::new(
+            MockTransport::new(1_000, &[(pks[0], Some(10))], &[]).with_failures(vec![transient()]),
+        );
+        let testnet = Arc::new(
+            MockTransport::new(2_000, &[(pks[0], Some(20))], &[]).with_failures(vec![permanent()]),
+        );
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string(), SCFS_TESTNET.to_string()]),
+                ..Default::default()
+            }),
+            HashMap::from([
+                (
+                    SCFS_DEVNET.to_string(),
+                    devnet.clone() as Arc<dyn ScfsTransport>,
+                ),
+                (
+                    SCFS_TESTNET.to_string(),
+                    testnet.clone() as Arc<dyn ScfsTransport>,
+                ),
+            ]),
+        )
+        .unwrap();
+        assert!(matches!(
+            my_matrix.run().await,
+            Err(ScfsError::RpcError { cluster, .. }) if cluster == *SCFS_TESTNET
+        ));
+        // The transient failure is retried, the permanent one is not
+        assert_eq!(devnet.account_requests(), 2);
+        assert_eq!(testnet.account_requests(), 1);
+        assert_eq!(
+            my_matrix.get_result_rows()[0].status(),
+            &vec![ScfsStatus::Active(10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn timeout_fail() {
+        // Accepts connections but never answers them
+        let dead = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+            clusters: Some(vec!["dead".to_string()]),
+            custom_clusters: HashMap::from([(
+                "dead".to_string(),
+                format!("http://{}", dead.local_addr().unwrap()),
+            )]),
+            timeout: Some(Duration::from_secs(1)),
+            retry: None,
+            ..Default::default()
+        }))
+        .unwrap();
+        let started = std::time::Instant::now();
+        assert!(matches!(
+            my_matrix.run().await,
+            Err(ScfsError::Timeout { cluster }) if cluster == "dead"
+        ));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(my_matrix.is_incomplete());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn mock_transport_timeout_fail() {
+        let pks = &SCFS_FEATURE_PKS[..1];
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                timeout: Some(Duration::from_secs(1)),
+                retry: Some(RetryPolicy::new(2, Duration::from_secs(1))),
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_DEVNET.to_string(),
+                Arc::new(
+                    MockTransport::new(1_000, &[(pks[0], Some(10))], &[])
+                        .with_delay(Duration::from_secs(60)),
+                ) as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        let started = tokio::time::Instant::now();
+        assert!(matches!(
+            my_matrix.run().await,
+            Err(ScfsError::Timeout { cluster }) if cluster == *SCFS_DEVNET
+        ));
+        // Timeouts are transient, the request is made twice
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_policy_pass() {
+        let pks = &SCFS_FEATURE_PKS[..1];
+        let transient = || {
+            ClientError::from(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "connection reset",
+            ))
+        };
+        for (retry, requests, populated) in [
+            (Some(RetryPolicy::new(3, Duration::from_secs(1))), 3, true),
+            (None, 1, false),
+        ] {
+            let devnet = Arc::new(
+                MockTransport::new(1_000, &[(pks[0], Some(10))], &[])
+                    .with_failures(vec![transient(), transient()]),
+            );
+            let mut my_matrix = ScfsMatrix::new_with_transports(
+                Some(ScfsCriteria {
+                    features: Some(pks.to_vec()),
+                    clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                    retry,
+                    ..Default::default()
+                }),
+                HashMap::from([(
+                    SCFS_DEVNET.to_string(),
+                    devnet.clone() as Arc<dyn ScfsTransport>,
+                )]),
+            )
+            .unwrap();
+            let started = tokio::time::Instant::now();
+            assert_eq!(my_matrix.run().await.is_ok(), populated);
+            assert_eq!(devnet.account_requests(), requests);
+            if populated {
+                assert_eq!(
+                    my_matrix.get_result_rows()[0].status(),
+                    &vec![ScfsStatus::Active(10)]
+                );
+                // Backing off 1s then 2s, each less up to half for jitter
+                let elapsed = started.elapsed();
+                assert!(elapsed >= Duration::from_millis(1_500));
+                assert!(elapsed <= Duration::from_secs(3));
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_with_progress_pass() {
+        let pks = &SCFS_FEATURE_PKS[..2];
+        // devnet answers last, well after testnet
+        let transports: HashMap<String, Arc<dyn ScfsTransport>> = HashMap::from([
+            (
+                SCFS_DEVNET.to_string(),
+                Arc::new(
+                    MockTransport::new(1_000, &[(pks[0], Some(10)), (pks[1], None)], &[])
+                        .with_delay(Duration::from_secs(3)),
+                ) as Arc<dyn ScfsTransport>,
+            ),
+            (
+                SCFS_TESTNET.to_string(),
+                Arc::new(
+                    MockTransport::new(2_000, &[(pks[1], Some(20))], &[])
+                        .with_delay(Duration::from_secs(1)),
+                ) as Arc<dyn ScfsTransport>,
+            ),
+        ]);
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![
+                    SCFS_DEVNET.to_string(),
+                    SCFS_TESTNET.to_string(),
+                    SCFS_LOCAL.to_string(),
+                ]),
+                faux_local: true,
+                ..Default::default()
+            }),
+            transports,
+        )
+        .unwrap();
+        let started = tokio::time::Instant::now();
+        let mut progress = Vec::<(String, Vec<ScfsStatus>, Duration)>::new();
+        my_matrix
+            .run_with_progress(|cluster, statuses| {
+                progress.push((cluster.to_string(), statuses.to_vec(), started.elapsed()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            progress,
+            vec![
+                (
+                    SCFS_LOCAL.to_string(),
+                    vec![ScfsStatus::Active(0), ScfsStatus::Active(0)],
+                    Duration::ZERO
+                ),
+                (
+                    SCFS_TESTNET.to_string(),
+                    vec![ScfsStatus::Inactive, ScfsStatus::Active(20)],
+                    Duration::from_secs(2)
+                ),
+                (
+                    SCFS_DEVNET.to_string(),
+                    vec![ScfsStatus::Active(10), ScfsStatus::Pending],
+                    Duration::from_secs(6)
+                ),
+            ]
+        );
+        // Rows keep the criteria's cluster order
+        assert_eq!(
+            my_matrix.get_result_rows()[0].status(),
+            &vec![
+                ScfsStatus::Active(10),
+                ScfsStatus::Inactive,
+                ScfsStatus::Active(0)
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_clusters_pass() {
+        let pks = &SCFS_FEATURE_PKS[..2];
+        // devnet answers last, well after testnet
+        let transports: HashMap<String, Arc<dyn ScfsTransport>> = HashMap::from([
+            (
+                SCFS_DEVNET.to_string(),
+                Arc::new(
+                    MockTransport::new(1_000, &[(pks[0], Some(10)), (pks[1], None)], &[])
+                        .with_delay(Duration::from_secs(3)),
+                ) as Arc<dyn ScfsTransport>,
+            ),
+            (
+                SCFS_TESTNET.to_string(),
+                Arc::new(
+                    MockTransport::new(2_000, &[(pks[1], Some(20))], &[])
+                        .with_delay(Duration::from_secs(1)),
+                ) as Arc<dyn ScfsTransport>,
+            ),
+        ]);
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![
+                    SCFS_LOCAL.to_string(),
+                    SCFS_DEVNET.to_string(),
+                    SCFS_TESTNET.to_string(),
+                ]),
+                faux_local: true,
+                ..Default::default()
+            }),
+            transports,
+        )
+        .unwrap();
+        let started = tokio::time::Instant::now();
+        my_matrix.run().await.unwrap();
+        // Queried together, the run takes as long as the slowest cluster
+        assert_eq!(started.elapsed(), Duration::from_secs(6));
+        let statuses: Vec<_> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| r.status().clone())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Active(10),
+                    ScfsStatus::Inactive
+                ],
+                vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(20)
+                ],
+            ]
+        );
+        assert_eq!(my_matrix.get_current_slots()[&*SCFS_DEVNET], 1_000);
+        assert_eq!(my_matrix.get_current_slots()[&*SCFS_TESTNET], 2_000);
+    }
+
+    #[tokio::test]
+    async fn mock_transport_statuses_pass() {
+        let pks = &SCFS_FEATURE_PKS[..4];
+        let transports: HashMap<String, Arc<dyn ScfsTransport>> = HashMap::from([
+            (
+                SCFS_DEVNET.to_string(),
+                Arc::new(MockTransport::new(
+                    1_000,
+                    &[(pks[0], Some(10)), (pks[1], None)],
+                    &[pks[2]],
+                )) as Arc<dyn ScfsTransport>,
+            ),
+            (
+                SCFS_TESTNET.to_string(),
+                Arc::new(MockTransport::new(
+                    2_000,
+                    &[(pks[0], Some(20)), (pks[1], Some(21)), (pks[2], None)],
+                    &[],
+                )),
+            ),
+            (
+                SCFS_MAINNET.to_string(),
+                Arc::new(MockTransport::new(3_000, &[], &[])),
+            ),
+        ]);
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![
+                    SCFS_LOCAL.to_string(),
+                    SCFS_DEVNET.to_string(),
+                    SCFS_TESTNET.to_string(),
+                    SCFS_MAINNET.to_string(),
+                ]),
+                keep_decoded: true,
+                batch_size: 3,
+                faux_local: true,
+                ..Default::default()
+            }),
+            transports,
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        let statuses: Vec<&Vec<ScfsStatus>> = my_matrix
+            .get_result_rows()
+            .iter()
+            .map(|r| r.status())
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                &vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Active(10),
+                    ScfsStatus::Active(20),
+                    ScfsStatus::Inactive
+                ],
+                &vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Pending,
+                    ScfsStatus::Active(21),
+                    ScfsStatus::Inactive
+                ],
+                &vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Pending,
+                    ScfsStatus::Inactive
+                ],
+                &vec![
+                    ScfsStatus::Active(0),
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Inactive,
+                    ScfsStatus::Inactive
+                ],
+            ]
+        );
+        let rows = my_matrix.get_result_rows();
+        assert_eq!(rows[1].raw_feature(1).unwrap().activated_at, None);
+        assert!(rows[2].raw_feature(1).is_none());
+        assert!(rows[3].raw_feature(1).is_none());
+        assert_eq!(
+            my_matrix.get_current_slots(),
+            &HashMap::from([
+                (SCFS_LOCAL.to_string(), 0),
+                (SCFS_DEVNET.to_string(), 1_000),
+                (SCFS_TESTNET.to_string(), 2_000),
+                (SCFS_MAINNET.to_string(), 3_000),
+            ])
+        );
+        assert_eq!(
+            my_matrix
+                .validate_features_on_cluster(&SCFS_DEVNET)
+                .await
+                .unwrap(),
+            vec![pks[2], pks[3]]
+        );
+    }
+
+    #[test]
+    fn activation_epoch_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_MAINNET],
+            vec![vec![ScfsStatus::Active(864_001), ScfsStatus::Pending]],
+        );
+        for cluster in [&*SCFS_DEVNET, &*SCFS_MAINNET] {
+            my_matrix
+                .epoch_schedules
+                .insert(cluster.clone(), EpochSchedule::default());
+        }
+        // The default schedule's warmup epochs end at epoch 14, slot 524_256
+        let key = SCFS_FEATURE_PKS[0];
+        assert_eq!(
+            my_matrix.activation_epoch(&key, &SCFS_DEVNET).unwrap(),
+            Some(14)
+        );
+        assert_eq!(
+            my_matrix.activation_epoch(&key, &SCFS_MAINNET).unwrap(),
+            None
+        );
+        assert_eq!(
+            estimated_slot_time(864_001, 1_600_000_000, Duration::from_millis(400)),
+            1_600_345_600
+        );
+    }
+
+    #[tokio::test]
+    async fn run_resolve_epochs_pass() {
+        let pks = &SCFS_FEATURE_PKS[..2];
+        let devnet = MockTransport {
+            epoch_schedule: EpochSchedule::custom(100, 100, false),
+            ..MockTransport::new(1_000, &[(pks[0], Some(250)), (pks[1], None)], &[])
+        };
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                resolve_epochs: true,
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_DEVNET.to_string(),
+                Arc::new(devnet) as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        assert_eq!(my_matrix.slots_per_epoch(&SCFS_DEVNET), None);
+        assert!(matches!(
+            my_matrix.activation_epoch(&pks[0], &SCFS_DEVNET),
+            Err(ScfsError::EpochScheduleMissingError(_))
+        ));
+        my_matrix.run().await.unwrap();
+        assert_eq!(my_matrix.slots_per_epoch(&SCFS_DEVNET), Some(100));
+        assert_eq!(
+            my_matrix.activation_epoch(&pks[0], &SCFS_DEVNET).unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            my_matrix.activation_epoch(&pks[1], &SCFS_DEVNET).unwrap(),
+            None
+        );
+        assert!(matches!(
+            my_matrix.activation_epoch(&pks[0], &SCFS_TESTNET),
+            Err(ScfsError::ClusterNotQueriedError(_))
+        ));
+        // Under a deadline the epochs are resolved too
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(my_matrix.get_criteria().clone()),
+            HashMap::from([(
+                SCFS_DEVNET.to_string(),
+                Arc::new(MockTransport {
+                    epoch_schedule: EpochSchedule::custom(100, 100, false),
+                    ..MockTransport::new(1_000, &[(pks[0], Some(250)), (pks[1], None)], &[])
+                }) as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        my_matrix
+            .run_with_deadline(Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert_eq!(
+            my_matrix.activation_epoch(&pks[0], &SCFS_DEVNET).unwrap(),
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn local_cluster_epochs_pass() {
+        let pks = &SCFS_FEATURE_PKS[..1];
+        let local = MockTransport {
+            epoch_schedule: EpochSchedule::custom(100, 100, false),
+            ..MockTransport::new(1_000, &[(pks[0], Some(250))], &[])
+        };
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_LOCAL.to_string()]),
+                resolve_epochs: true,
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_LOCAL.to_string(),
+                Arc::new(local) as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        // A queried local cluster uses its own schedule and block times
+        assert_eq!(my_matrix.slots_per_epoch(&SCFS_LOCAL), Some(100));
+        assert_eq!(
+            my_matrix.activation_epoch(&pks[0], &SCFS_LOCAL).unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            my_matrix.timeline().await.unwrap()[0].entries[0].activation_time,
+            Some(1_600_000_250)
+        );
+    }
+
+    #[tokio::test]
+    async fn features_by_activation_epoch_pass() {
+        let pks = &SCFS_FEATURE_PKS[..4];
+        let devnet = MockTransport {
+            epoch_schedule: EpochSchedule::custom(100, 100, false),
+            ..MockTransport::new(
+                1_000,
+                &[
+                    (pks[0], Some(10)),
+                    (pks[1], Some(150)),
+                    (pks[2], None),
+                    (pks[3], Some(199)),
+                ],
+                &[],
+            )
+        };
+        let mut my_matrix = ScfsMatrix::new_with_transports(
+            Some(ScfsCriteria {
+                features: Some(pks.to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                ..Default::default()
+            }),
+            HashMap::from([(
+                SCFS_DEVNET.to_string(),
+                Arc::new(devnet) as Arc<dyn ScfsTransport>,
+            )]),
+        )
+        .unwrap();
+        my_matrix.run().await.unwrap();
+        assert!(matches!(
+            my_matrix.features_by_activation_epoch(&SCFS_DEVNET),
+            Err(ScfsError::EpochScheduleMissingError(_))
+        ));
+        assert!(matches!(
+            my_matrix.features_by_activation_epoch(&SCFS_TESTNET),
+            Err(ScfsError::ClusterNotQueriedError(_))
+        ));
+        my_matrix.resolve_epochs().await.unwrap();
+        assert_eq!(
+            my_matrix
+                .features_by_activation_epoch(&SCFS_DEVNET)
+                .unwrap(),
+            BTreeMap::from([(0, vec![pks[0]]), (1, vec![pks[1], pks[3]])])
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_features_on_cluster_pass() {
+        // Feature { activated_at: Some(42) } serialized with bincode, then no account
+        let mock = RpcClient::new_mock_with_mocks(
+            "succeeds".to_string(),
+            HashMap::from([(
+                RpcRequest::GetMultipleAccounts,
+                json!({
+                    "context": {"slot": 500},
+                    "value": [{
+                        "lamports": 1,
+                        "data": ["ASoAAAAAAAAA", "base64"],
+                        "owner": feature::id().to_string(),
+                        "executable": false,
+                        "rentEpoch": 0,
+                        "space": 9
+                    }, null]
+                }),
+            )]),
+        );
+        let my_matrix = ScfsMatrix::new_with_clients(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                faux_local: true,
+                ..Default::default()
+            }),
+            HashMap::from([(SCFS_DEVNET.to_string(), Arc::new(mock))]),
+        )
+        .unwrap();
+        assert_eq!(
+            my_matrix
+                .validate_features_on_cluster(&SCFS_DEVNET)
+                .await
+                .unwrap(),
+            vec![SCFS_FEATURE_PKS[1]]
+        );
+        assert!(my_matrix
+            .validate_features_on_cluster(&SCFS_LOCAL)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            my_matrix.validate_features_on_cluster("moonnet").await,
+            Err(ScfsError::UnrecognizedCriteriaTypeError { .. })
+        ));
+    }
+
+    /// Sender that never answers within the tests' deadlines
+    struct SlowSender;
+
+    #[async_trait::async_trait]
+    impl RpcSender for SlowSender {
+        async fn send(
+            &self,
+            _request: RpcRequest,
+            _params: serde_json::Value,
+        ) -> solana_client::client_error::Result<serde_json::Value> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(json!(0))
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            "slow".to_string()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_with_deadline_fail() {
+        let slow = RpcClient::new_sender(SlowSender, RpcClientConfig::default());
+        let mut my_matrix = ScfsMatrix::new_with_clients(
+            Some(ScfsCriteria {
+                features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+                clusters: Some(vec![SCFS_LOCAL.to_string(), SCFS_DEVNET.to_string()]),
+                faux_local: true,
+                ..Default::default()
+            }),
+            HashMap::from([(SCFS_DEVNET.to_string(), Arc::new(slow))]),
+        )
+        .unwrap();
+        assert!(matches!(
+            my_matrix.run_with_deadline(Duration::from_secs(10)).await,
+            Err(ScfsError::DeadlineExceeded(d)) if d == Duration::from_secs(10)
+        ));
+        assert!(my_matrix.is_incomplete());
+        for row in my_matrix.get_result_rows() {
+            assert_eq!(row.status(), &vec![ScfsStatus::Active(0)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn timeline_pass() {
+        let mut my_matrix = faux_matrix(
+            &[&SCFS_DEVNET, &SCFS_TESTNET],
+            vec![vec![ScfsStatus::Active(100), ScfsStatus::Pending]],
+        );
+        my_matrix.transports.0.insert(
+            SCFS_DEVNET.to_string(),
+            Arc::new(RpcClient::new_mock_with_mocks(
+                "succeeds".to_string(),
+                HashMap::from([(RpcRequest::GetBlockTime, json!(1_650_000_000))]),
+            )),
+        );
+        my_matrix.transports.0.insert(
+            SCFS_TESTNET.to_string(),
+            Arc::new(RpcClient::new_mock("fails".to_string())),
+        );
+        assert_eq!(
+            my_matrix.timeline().await.unwrap(),
+            vec![FeatureTimeline {
+                feature_key: SCFS_FEATURE_PKS[0],
+                entries: vec![
+                    ClusterTimelineEntry {
+                        cluster: SCFS_DEVNET.to_string(),
+                        status: ScfsStatus::Active(100),
+                        activation_slot: Some(100),
+                        activation_time: Some(1_650_000_000),
+                    },
+                    ClusterTimelineEntry {
+                        cluster: SCFS_TESTNET.to_string(),
+                        status: ScfsStatus::Pending,
+                        activation_slot: None,
+                        activation_time: None,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn custom_clusters_pass() {
+        let criteria = ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..1].to_vec()),
+            clusters: Some(vec![SCFS_DEVNET.to_string(), "mycluster".to_string()]),
+            custom_clusters: HashMap::from([(
+                "mycluster".to_string(),
+                "http://127.0.0.1:9001".to_string(),
+            )]),
+            ..Default::default()
+        };
+        let my_matrix = ScfsMatrix::new(Some(criteria.clone())).unwrap();
+        let urls = my_matrix.resolved_urls();
+        assert_eq!(urls["mycluster"], "http://127.0.0.1:9001");
+        assert_eq!(urls[&*SCFS_DEVNET], SCFS_URL_LOOKUPS[&*SCFS_DEVNET]);
+
+        assert!(matches!(
+            ScfsMatrix::new(Some(ScfsCriteria {
+                clusters: Some(vec!["othercluster".to_string()]),
+                ..criteria.clone()
+            })),
+            Err(ScfsError::UnrecognizedCriteriaTypeError {
+                ctype: "cluster",
+                ..
+            })
+        ));
+        assert!(matches!(
+            ScfsMatrix::new(Some(ScfsCriteria {
+                custom_clusters: HashMap::from([(
+                    SCFS_DEVNET.to_string(),
+                    "http://127.0.0.1:9001".to_string(),
+                )]),
+                ..criteria
+            })),
+            Err(ScfsError::ReservedClusterNameError(name)) if name == *SCFS_DEVNET
+        ));
+    }
+
+    #[test]
+    fn custom_features_pass() {
+        let announced = Pubkey::new_unique();
+        let criteria = ScfsCriteria {
+            features: Some(vec![SCFS_FEATURE_PKS[0], announced]),
+            clusters: Some(vec![SCFS_LOCAL.to_string()]),
+            ..Default::default()
+        };
+        assert!(ScfsMatrix::new(Some(criteria.clone())).is_err());
+        let my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            custom_features: HashMap::from([(announced, "just announced".to_string())]),
+            ..criteria
+        }))
+        .unwrap();
+        let rows = my_matrix.get_result_rows();
+        assert_eq!(rows[1].key(), &announced);
+        assert_eq!(rows[1].desc(), "just announced");
+        assert_ne!(rows[0].desc(), "just announced");
+        let descriptions = my_matrix.descriptions();
+        assert_eq!(descriptions.len(), rows.len());
+        for row in rows {
+            assert_eq!(descriptions[row.key()], row.desc());
+        }
+        assert_eq!(descriptions[&announced], "just announced");
+    }
+
+    #[test]
+    fn exclude_description_pass() {
+        let announced = Pubkey::new_unique();
+        let my_matrix = ScfsMatrix::new(Some(
+            ScfsCriteria {
+                features: Some(vec![SCFS_FEATURE_PKS[0], announced]),
+                clusters: Some(vec![SCFS_DEVNET.to_string()]),
+                custom_features: HashMap::from([(announced, "just announced".to_string())]),
+                ..Default::default()
+            }
+            .include_description(false),
+        ))
+        .unwrap();
+        assert!(my_matrix
+            .get_result_rows()
+            .iter()
+            .all(|r| r.desc().is_empty()));
+        let dir = std::env::temp_dir().join(format!("scfs_undescribed_{}", std::process::id()));
+        my_matrix
+            .export_per_cluster(&dir, ExportFormat::Csv)
+            .unwrap();
+        let csv = std::fs::read_to_string(dir.join("devnet.csv")).unwrap();
+        assert_eq!(csv.lines().next(), Some("feature,status,activation_slot"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn public_clusters_pass() {
+        assert_eq!(SCFS_PUBLIC_CLUSTER_LIST.len(), 3);
+        assert!(!SCFS_PUBLIC_CLUSTER_LIST.contains(&SCFS_LOCAL));
+        let my_matrix = ScfsMatrix::new(Some(ScfsCriteria::default().public_clusters())).unwrap();
+        assert_eq!(
+            my_matrix.get_criteria().clusters,
+            Some(vec![
+                SCFS_DEVNET.to_string(),
+                SCFS_TESTNET.to_string(),
+                SCFS_MAINNET.to_string()
+            ])
+        );
     }
 }
@@ -1,5 +1,12 @@
 //! @brief Error sets for Scfs
 
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_custom_error::JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    rpc_request::RpcError,
+};
+use solana_sdk::clock::Slot;
+use std::time::Duration;
 use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum ScfsError {
@@ -10,6 +17,92 @@ pub enum ScfsError {
         ctype: &'static str,
         element: Vec<String>,
     },
+    #[error("Feature names {0:?} are shared by several features, use their keys")]
+    AmbiguousFeatureNameError(Vec<String>),
+    #[error("Failed reading feature list at line {line}")]
+    FeatureListReadError { line: usize, source: std::io::Error },
+    #[error("Failed reading config file {path}")]
+    ConfigReadError {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Invalid {entry} in config file {path}: {reason}")]
+    ConfigEntryError {
+        path: String,
+        entry: String,
+        reason: String,
+    },
+    #[error("Invalid feature key {key} at line {line}")]
+    InvalidFeatureKeyError { line: usize, key: String },
+    #[error("Custom cluster {0} uses a reserved cluster name")]
+    ReservedClusterNameError(String),
+    #[error("Cluster {0} was not queried")]
+    ClusterNotQueriedError(String),
+    #[error("Epoch schedule of {0} was not resolved")]
+    EpochScheduleMissingError(String),
+    #[error("Failed getting block time for slot {slot} on {cluster}")]
+    BlockTimeError {
+        cluster: String,
+        slot: Slot,
+        source: Box<ClientError>,
+    },
+    #[error("RPC request to {cluster} failed")]
+    RpcError {
+        cluster: String,
+        source: Box<ClientError>,
+    },
+    #[error("RPC request to {cluster} timed out")]
+    Timeout { cluster: String },
+    #[error("Cluster column has {found} statuses for {expected} features")]
+    ColumnLengthError { expected: usize, found: usize },
+    #[error("Run did not complete within {0:?}")]
+    DeadlineExceeded(Duration),
+    #[error("Failed serializing to JSON")]
+    JsonError { source: serde_json::Error },
+    #[error("Failed exporting to {path}")]
+    ExportError {
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+impl ScfsError {
+    /// Whether the error may clear on retry: RPC transport failures, timeouts,
+    /// rate limiting, server errors and unhealthy nodes. Everything else (e.g.
+    /// bad criteria or an unknown cluster) is permanent
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ScfsError::Timeout { .. } => true,
+            ScfsError::RpcError { source, .. } | ScfsError::BlockTimeError { source, .. } => {
+                match source.kind() {
+                    ClientErrorKind::Io(_) => true,
+                    ClientErrorKind::Reqwest(e) => {
+                        e.is_timeout()
+                            || e.is_connect()
+                            || e.status()
+                                .is_some_and(|s| s.as_u16() == 429 || s.is_server_error())
+                    }
+                    ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => {
+                        *code == JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a RPC request failed for want of a connection to the cluster
+    pub fn is_connection_failure(&self) -> bool {
+        match self {
+            ScfsError::RpcError { source, .. } => match source.kind() {
+                ClientErrorKind::Io(e) => e.kind() == std::io::ErrorKind::ConnectionRefused,
+                ClientErrorKind::Reqwest(e) => e.is_connect(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
 }
 
 pub type ScfsResult<T> = std::result::Result<T, ScfsError>;
@@ -0,0 +1,75 @@
+//! @brief Cluster transport for Scfs
+
+use async_trait::async_trait;
+use solana_client::{
+    client_error::{ClientErrorKind, Result as ClientResult},
+    nonblocking::rpc_client::RpcClient,
+};
+use solana_sdk::{
+    account::Account,
+    clock::{Slot, UnixTimestamp},
+    commitment_config::CommitmentConfig,
+    epoch_schedule::EpochSchedule,
+    pubkey::Pubkey,
+};
+
+/// Fetches what a ScfsMatrix run needs from a cluster, implemented over
+/// RpcClient and by deterministic mocks for offline tests
+#[async_trait]
+pub trait ScfsTransport: Send + Sync {
+    /// The cluster's current slot at 'commitment'
+    async fn get_slot(&self, commitment: CommitmentConfig) -> ClientResult<Slot>;
+
+    /// The accounts of 'keys' in order at 'commitment', None where an
+    /// account does not exist
+    async fn get_multiple_accounts(
+        &self,
+        keys: &[Pubkey],
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<Account>>>;
+
+    /// The cluster's epoch schedule
+    async fn get_epoch_schedule(&self) -> ClientResult<EpochSchedule>;
+
+    /// The block time of 'slot', transports without block times fail
+    async fn get_block_time(&self, _slot: Slot) -> ClientResult<UnixTimestamp> {
+        Err(
+            ClientErrorKind::Custom("block times are not served by this transport".to_string())
+                .into(),
+        )
+    }
+
+    /// The url of the cluster, if the transport has one
+    fn url(&self) -> Option<String> {
+        None
+    }
+}
+
+#[async_trait]
+impl ScfsTransport for RpcClient {
+    async fn get_slot(&self, commitment: CommitmentConfig) -> ClientResult<Slot> {
+        RpcClient::get_slot_with_commitment(self, commitment).await
+    }
+
+    async fn get_multiple_accounts(
+        &self,
+        keys: &[Pubkey],
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        RpcClient::get_multiple_accounts_with_commitment(self, keys, commitment)
+            .await
+            .map(|response| response.value)
+    }
+
+    async fn get_epoch_schedule(&self) -> ClientResult<EpochSchedule> {
+        RpcClient::get_epoch_schedule(self).await
+    }
+
+    async fn get_block_time(&self, slot: Slot) -> ClientResult<UnixTimestamp> {
+        RpcClient::get_block_time(self, slot).await
+    }
+
+    fn url(&self) -> Option<String> {
+        Some(RpcClient::url(self))
+    }
+}
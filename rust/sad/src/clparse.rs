@@ -1,12 +1,13 @@
 //! @brief command line setup and parse
 
 use {
+    crate::presets::{preset_descriptor, SAD_PRESET_NAMES},
     clap::{
         crate_description, crate_name, crate_version, App, AppSettings, Arg, ArgGroup, ArgMatches,
     },
     gadgets_common::load_yaml_file,
     lazy_static::*,
-    solana_clap_utils::input_validators::{is_keypair, is_pubkey, is_url_or_moniker},
+    solana_clap_utils::input_validators::{is_keypair, is_parsable, is_pubkey, is_url_or_moniker},
     solana_sdk::{pubkey::Pubkey, signature::read_keypair_file, signer::Signer},
     std::process::exit,
     std::{collections::HashMap, str::FromStr},
@@ -61,6 +62,15 @@ pub fn parse_command_line() -> App<'static, 'static> {
                 .global(true)
                 .help("YAML data deserialization declaration file"),
         )
+        .arg(
+            Arg::with_name("preset")
+                .long("preset")
+                .global(true)
+                .takes_value(true)
+                .conflicts_with("decl")
+                .possible_values(SAD_PRESET_NAMES)
+                .help("Packaged declaration to use instead of '--declfile'"),
+        )
         .arg(
             Arg::with_name("keypair")
                 .long("keypair")
@@ -96,7 +106,7 @@ pub fn parse_command_line() -> App<'static, 'static> {
                 .short("o")
                 .global(true)
                 .takes_value(true)
-                .possible_values(&["json", "stdout"])
+                .possible_values(&["json", "stdout", "flat"])
                 .default_value("stdout")
                 .requires_ifs(&[("json", "filename")])
                 .help("Direct output to file"),
@@ -110,8 +120,109 @@ pub fn parse_command_line() -> App<'static, 'static> {
                 .requires("output")
                 .help("Filename for '-o json' output"),
         )
+        .arg(
+            Arg::with_name("select")
+                .long("select")
+                .global(true)
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Print only the value at a '.' separated path, e.g. owner.authority or votes[0]"),
+        )
+        .arg(
+            Arg::with_name("retries")
+                .long("retries")
+                .global(true)
+                .takes_value(true)
+                .value_name("COUNT")
+                .default_value("3")
+                .validator(is_parsable::<u32>)
+                .help("Attempts made at the program accounts scan"),
+        )
+        .arg(
+            Arg::with_name("sort")
+                .long("sort")
+                .global(true)
+                .takes_value(true)
+                .possible_values(&["pubkey", "size"])
+                .default_value("pubkey")
+                .help("Order of the program accounts, by pubkey or by data size"),
+        )
+        .arg(
+            Arg::with_name("threads")
+                .long("threads")
+                .global(true)
+                .takes_value(true)
+                .value_name("COUNT")
+                .default_value("0")
+                .validator(is_parsable::<usize>)
+                .help("Threads deserializing program accounts, 0 for one per CPU"),
+        )
+        .arg(
+            Arg::with_name("numbers_as_strings")
+                .long("numbers-as-strings")
+                .global(true)
+                .takes_value(false)
+                .help("Render integer fields as strings in '-o json' output"),
+        )
+        .arg(
+            Arg::with_name("max_bytes")
+                .long("max-bytes")
+                .global(true)
+                .takes_value(true)
+                .value_name("N")
+                .validator(is_parsable::<usize>)
+                .help("Only deserialize the first N bytes of account data, later fields are unavailable"),
+        )
+        .arg(
+            Arg::with_name("rent")
+                .long("rent")
+                .global(true)
+                .takes_value(false)
+                .help("Include each account's rent exemption status and minimum balance"),
+        )
+        .arg(
+            Arg::with_name("ui_amount")
+                .long("ui-amount")
+                .global(true)
+                .takes_value(false)
+                .requires("preset")
+                .help("With '--preset token-account', fetch each account's mint and include its 'ui_amount'"),
+        )
         .subcommand(App::new("account").about("Deserialize single account"))
         .subcommand(App::new("program").about("Deserialize all program owned accounts"))
+        .subcommand(
+            App::new("identify")
+                .about("Report which candidate declarations decode a single account")
+                .arg(
+                    Arg::with_name("decls")
+                        .long("decls")
+                        .takes_value(true)
+                        .multiple(true)
+                        .required(true)
+                        .value_name("FILE")
+                        .help("Candidate YAML data deserialization declaration files"),
+                ),
+        )
+        .subcommand(
+            App::new("diff")
+                .about("Report the fields that differ between two accounts of the same layout")
+                .arg(
+                    Arg::with_name("pubkey_a")
+                        .index(1)
+                        .required(true)
+                        .validator(is_pubkey)
+                        .value_name("PUBKEY_A")
+                        .help("Account compared from"),
+                )
+                .arg(
+                    Arg::with_name("pubkey_b")
+                        .index(2)
+                        .required(true)
+                        .validator(is_pubkey)
+                        .value_name("PUBKEY_B")
+                        .help("Account compared to"),
+                ),
+        )
         .group(
             ArgGroup::with_name("key_flags").args(&["keypair", "pkstr", "sampkey"]), // .required(true),
         )
@@ -148,69 +259,83 @@ lazy_static! {
     };
 }
 
+/// Get the packaged preset descriptor if requested, otherwise
+/// load the descriptor file
+fn get_descriptor(matches: &ArgMatches, descriptor_file_name: Option<&str>) -> Vec<Yaml> {
+    if let Some(preset) = matches.value_of("preset") {
+        return preset_descriptor(preset).unwrap();
+    }
+    load_descriptor_file(descriptor_file_name.unwrap())
+}
+
+/// Load the descriptor file, exiting on failure
+fn load_descriptor_file(descriptor_file_name: &str) -> Vec<Yaml> {
+    let indecl = load_yaml_file(descriptor_file_name);
+    if indecl.is_err() {
+        eprintln!(
+            "File error: On {} {}",
+            descriptor_file_name,
+            indecl.err().unwrap()
+        );
+        exit(1)
+    } else {
+        indecl.unwrap()
+    }
+}
+
+/// Load each candidate descriptor file named by 'decls'
+pub fn get_candidate_descriptors(matches: &ArgMatches) -> Vec<(String, Vec<Yaml>)> {
+    matches
+        .values_of("decls")
+        .unwrap()
+        .map(|name| (name.to_string(), load_descriptor_file(name)))
+        .collect()
+}
+
 /// Get correct public key from command line and
-/// associated sample descriptor or provided descriptor
+/// associated sample descriptor, preset or provided descriptor
 /// path
 pub fn get_account_and_descriptor(matches: &ArgMatches) -> (Pubkey, Vec<Yaml>) {
+    let kp = get_target_pubkey(matches);
+    if matches.is_present("sampkey") {
+        (
+            kp,
+            get_descriptor(matches, SAMPLE_KEYS_MAP.get("decl").copied()),
+        )
+    } else {
+        (kp, get_descriptor(matches, matches.value_of("decl")))
+    }
+}
+
+/// Get the two accounts to diff and the preset or provided descriptor
+pub fn get_diff_targets(matches: &ArgMatches) -> (Pubkey, Pubkey, Vec<Yaml>) {
+    (
+        Pubkey::from_str(matches.value_of("pubkey_a").unwrap()).unwrap(),
+        Pubkey::from_str(matches.value_of("pubkey_b").unwrap()).unwrap(),
+        get_descriptor(matches, matches.value_of("decl")),
+    )
+}
+
+/// Get the public key from the keypair, public key string
+/// or sample key on the command line
+pub fn get_target_pubkey(matches: &ArgMatches) -> Pubkey {
     let (kp, ks, ss) = (
         matches.is_present("keypair"),
         matches.is_present("pkstr"),
         matches.is_present("sampkey"),
     );
     match (kp, ks, ss) {
-        (true, _, _) => {
-            let descriptor_file_name = matches.value_of("decl").unwrap();
-            let kp = read_keypair_file(matches.value_of("keypair").unwrap())
-                .unwrap()
-                .pubkey();
-            let indecl = load_yaml_file(descriptor_file_name);
-            if indecl.is_err() {
-                eprintln!(
-                    "File error: On {} {}",
-                    descriptor_file_name,
-                    indecl.err().unwrap()
-                );
-                exit(1)
-            } else {
-                (kp, indecl.unwrap())
-            }
-        }
-        (_, true, _) => {
-            let descriptor_file_name = matches.value_of("decl").unwrap();
-            let kp = Pubkey::from_str(matches.value_of("pkstr").unwrap()).unwrap();
-            let indecl = load_yaml_file(descriptor_file_name);
-            if indecl.is_err() {
-                eprintln!(
-                    "File error: On {} {}",
-                    descriptor_file_name,
-                    indecl.err().unwrap()
-                );
-                exit(1)
-            } else {
-                (kp, indecl.unwrap())
-            }
-        }
-        (_, _, true) => {
-            let kp = read_keypair_file(
-                SAMPLE_KEYS_MAP
-                    .get(matches.value_of("sampkey").unwrap())
-                    .unwrap(),
-            )
+        (true, _, _) => read_keypair_file(matches.value_of("keypair").unwrap())
             .unwrap()
-            .pubkey();
-            let descriptor_file_name = *SAMPLE_KEYS_MAP.get("decl").unwrap();
-            let indecl = load_yaml_file(descriptor_file_name);
-            if indecl.is_err() {
-                eprintln!(
-                    "File error: On {} {}",
-                    descriptor_file_name,
-                    indecl.err().unwrap()
-                );
-                exit(1)
-            } else {
-                (kp, indecl.unwrap())
-            }
-        }
+            .pubkey(),
+        (_, true, _) => Pubkey::from_str(matches.value_of("pkstr").unwrap()).unwrap(),
+        (_, _, true) => read_keypair_file(
+            SAMPLE_KEYS_MAP
+                .get(matches.value_of("sampkey").unwrap())
+                .unwrap(),
+        )
+        .unwrap()
+        .pubkey(),
         _ => unreachable!(),
     }
 }
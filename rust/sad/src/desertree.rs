@@ -6,23 +6,134 @@
 use {
     crate::{
         errors::{SadTreeError, SadTreeResult},
-        sadtypes::{deser_value_for, from_scalar_value_for, is_sadvalue_type, SadValue},
+        presets::fragment_descriptor,
+        sadtypes::{
+            deser_value_for, from_scalar_value_for, is_sadvalue_type, to_f64_for, to_i128_for,
+            to_seed_bytes_for, to_usize_for, SadValue,
+        },
     },
-    borsh::BorshDeserialize,
     downcast_rs::{impl_downcast, Downcast},
     lazy_static::*,
     serde_json::{json, Value},
-    std::collections::HashMap,
+    solana_sdk::{account::Account, hash::hashv, pubkey::Pubkey},
+    std::{collections::HashMap, fmt, str::FromStr},
     yaml_rust::{yaml::Yaml, YamlLoader},
 };
 /// Simple Node for tree membership
-trait Node: std::fmt::Debug + Downcast {
+trait Node: std::fmt::Debug + Downcast + Send + Sync {
     /// Clone of the inbound yaml sad 'type'
     fn decl_type(&self) -> &String;
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>);
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()>;
 }
 impl_downcast!(Node);
 
+/// Account data being deserialized, 'data' is the unread
+/// remainder of 'base' (the whole account data), 'owner'
+/// the owning program of the account when known and 'fields'
+/// the descriptor's Data Sections decoded so far, 'saved'
+/// the positions Seek nodes moved away from and 'pointers'
+/// the targets of the Pointer nodes being decoded. 'truncated' is
+/// set when 'base' holds only the leading bytes of the account data
+/// and 'resolver' fetches the accounts FollowRef nodes reference
+#[derive(Debug)]
+pub struct SadCursor<'a> {
+    base: &'a [u8],
+    pub data: &'a [u8],
+    owner: Option<Pubkey>,
+    fields: HashMap<String, SadValue>,
+    saved: Vec<usize>,
+    pointers: Vec<usize>,
+    truncated: bool,
+    resolver: Option<&'a dyn SadAccountResolver>,
+}
+
+/// Fetches the accounts referenced by FollowRef nodes, None
+/// when an account is unavailable
+pub trait SadAccountResolver: Sync {
+    fn resolve(&self, key: &Pubkey) -> Option<Account>;
+}
+
+impl<F> SadAccountResolver for F
+where
+    F: Fn(&Pubkey) -> Option<Account> + Sync,
+{
+    fn resolve(&self, key: &Pubkey) -> Option<Account> {
+        self(key)
+    }
+}
+
+impl fmt::Debug for dyn SadAccountResolver + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SadAccountResolver")
+    }
+}
+
+impl<'a> SadCursor<'a> {
+    pub fn new(base: &'a [u8]) -> Self {
+        Self {
+            base,
+            data: base,
+            owner: None,
+            fields: HashMap::new(),
+            saved: Vec::new(),
+            pointers: Vec::new(),
+            truncated: false,
+            resolver: None,
+        }
+    }
+
+    /// Limits the data to its first 'max_bytes', when given,
+    /// with reads beyond treated as the end of the data
+    pub fn with_max_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        if let Some(max_bytes) = max_bytes.filter(|m| *m < self.base.len()) {
+            self.base = &self.base[..max_bytes];
+            self.data = self.base;
+            self.truncated = true;
+        }
+        self
+    }
+
+    /// A Data Section decoded before the current one
+    pub fn field(&self, name: &str) -> Option<&SadValue> {
+        self.fields.get(name)
+    }
+
+    pub fn with_owner(mut self, owner: &Pubkey) -> Self {
+        self.owner = Some(*owner);
+        self
+    }
+
+    pub fn owner(&self) -> Option<&Pubkey> {
+        self.owner.as_ref()
+    }
+
+    pub fn with_resolver(mut self, resolver: &'a dyn SadAccountResolver) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    /// Length of the whole account data
+    pub fn data_len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// Absolute position of the unread data
+    pub fn position(&self) -> usize {
+        self.base.len() - self.data.len()
+    }
+
+    /// Moves the unread data to an absolute position
+    pub fn seek(&mut self, position: usize) -> SadTreeResult<()> {
+        match self.base.get(position..) {
+            Some(data) => {
+                self.data = data;
+                Ok(())
+            }
+            None => Err(SadTreeError::SeekOutOfRange(position, self.base.len())),
+        }
+    }
+}
+
 /// Simple branch for tree membership
 trait NodeWithChildren: Node {
     fn children(&self) -> &Vec<Box<dyn Node>>;
@@ -32,10 +143,68 @@ impl_downcast!(NodeWithChildren);
 const SAD_YAML_TYPE: &str = "type";
 const SAD_YAML_NAME: &str = "name";
 const SAD_YAML_DESCRIPTOR: &str = "descriptor";
-// const SAD_YAML_SIZE_TYPE: &str = "size_type";
+const SAD_YAML_SIZE_TYPE: &str = "size_type";
+const SAD_YAML_SIZE: &str = "size";
 const SAD_YAML_CONTAINS: &str = "contains";
 const SAD_YAML_FIELDS: &str = "fields";
+const SAD_YAML_VARIANTS: &str = "variants";
+const SAD_YAML_DISCRIMINANT: &str = "discriminant";
+const SAD_YAML_OWNERS: &str = "owners";
+const SAD_YAML_OWNER: &str = "owner";
+const SAD_YAML_VERSIONS: &str = "versions";
+const SAD_YAML_VERSION: &str = "version";
+const SAD_YAML_VERSION_TYPE: &str = "version_type";
+const SAD_YAML_VERSION_OFFSET: &str = "version_offset";
+const SAD_YAML_START_OFFSET: &str = "start_offset";
+const SAD_YAML_ANCHOR_ACCOUNT: &str = "anchor_account";
+const SAD_YAML_ENCODING: &str = "encoding";
+/// Descriptor level attributes that sit alongside the descriptor id
+const SAD_TREE_ATTRIBUTES: &[&str] = &[
+    SAD_YAML_START_OFFSET,
+    SAD_YAML_ANCHOR_ACCOUNT,
+    SAD_YAML_ENCODING,
+];
+/// Encoding Data Sections are decoded with, borsh: little endian fixed width
+/// integers, u32 length prefixed String/Vec/HashMap and u8 Option/Enum tags
+const SAD_DEFAULT_ENCODING: &str = "borsh";
+/// Anchor prefixes account data with the first 8 bytes of sha256("account:<Name>")
+const SAD_ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+const SAD_YAML_COUNT_TYPE: &str = "count_type";
+const SAD_YAML_TAG_TYPE: &str = "tag_type";
+const SAD_YAML_MODE: &str = "mode";
+const SAD_YAML_STRICT: &str = "strict";
+const SAD_YAML_MAX_LEN: &str = "max_len";
+const SAD_YAML_BYTE_LENGTH: &str = "byte_length";
+const SAD_YAML_FLAGS: &str = "flags";
+const SAD_YAML_UNSET: &str = "unset";
+const SAD_YAML_PDA_CHECK: &str = "pda_check";
+const SAD_YAML_PROGRAM: &str = "program";
+const SAD_YAML_SEEDS: &str = "seeds";
+const SAD_YAML_ALGORITHM: &str = "algorithm";
+const SAD_YAML_START: &str = "start";
+const SAD_YAML_OFFSET: &str = "offset";
+const SAD_YAML_FIELD: &str = "field";
+const SAD_YAML_RELATIVE: &str = "relative";
+const SAD_YAML_END: &str = "end";
+const SAD_YAML_SCALE_NUMERATOR: &str = "scale_numerator";
+const SAD_YAML_SCALE_DENOMINATOR: &str = "scale_denominator";
+const SAD_YAML_TRAILER_SIZE: &str = "trailer_size";
+const SAD_YAML_TRAILER_OFFSET: &str = "trailer_offset";
 const SAD_NAMED_FIELD: &str = "NamedField";
+const SAD_VARIANT: &str = "Variant";
+const SAD_SCALED: &str = "Scaled";
+const SAD_ENUM: &str = "Enum";
+const SAD_PDA_CHECK: &str = "PdaCheck";
+/// Most bytes a 64 bit LEB128 integer occupies
+const SAD_LEB128_MAX_BYTES: usize = 10;
+/// Most fractional digits rendered for a scaled integer
+const SAD_SCALE_MAX_FRACTION_DIGITS: usize = 18;
+/// borsh defaults, u32 for lengths and u8 for tags
+const SAD_DEFAULT_SIZE_TYPE: &str = "U32";
+const SAD_DEFAULT_TAG_TYPE: &str = "U8";
+const SAD_SHORT_U16: &str = "ShortU16";
+/// Upper bound on the elements a RunLength may expand to
+const SAD_RUN_LENGTH_MAX_ELEMENTS: usize = 1 << 20;
 
 // Jump table for generalizing parse construction
 lazy_static! {
@@ -50,6 +219,27 @@ lazy_static! {
         jump_table.insert("CStruct".to_string(), SadStructure::from_yaml);
         jump_table.insert("NamedField".to_string(), SadNamedField::from_yaml);
         jump_table.insert("PublicKey".to_string(), SadPublicKey::from_yaml);
+        jump_table.insert("Array".to_string(), SadArray::from_yaml);
+        jump_table.insert("Option".to_string(), SadOption::from_yaml);
+        jump_table.insert("Enum".to_string(), SadEnum::from_yaml);
+        jump_table.insert("RunLength".to_string(), SadRunLength::from_yaml);
+        jump_table.insert("FillBetween".to_string(), SadFillBetween::from_yaml);
+        jump_table.insert("TrailerRef".to_string(), SadTrailerRef::from_yaml);
+        jump_table.insert("OwnerSwitch".to_string(), SadOwnerSwitch::from_yaml);
+        jump_table.insert("Versioned".to_string(), SadVersioned::from_yaml);
+        jump_table.insert("TaggedVec".to_string(), SadVector::tagged_from_yaml);
+        jump_table.insert("FlagString".to_string(), SadFlagString::from_yaml);
+        jump_table.insert("Checksum".to_string(), SadChecksum::from_yaml);
+        jump_table.insert("CString".to_string(), SadCString::from_yaml);
+        jump_table.insert("JsonText".to_string(), SadJsonText::from_yaml);
+        jump_table.insert("Timestamp".to_string(), SadTimestamp::from_yaml);
+        jump_table.insert("Fragment".to_string(), parse_fragment);
+        jump_table.insert("Seek".to_string(), SadSeek::from_yaml);
+        jump_table.insert("Restore".to_string(), SadSeek::from_yaml);
+        jump_table.insert("Pointer".to_string(), SadPointer::from_yaml);
+        jump_table.insert("FollowRef".to_string(), SadFollowRef::from_yaml);
+        jump_table.insert("Leb128U".to_string(), SadLeb128::from_yaml);
+        jump_table.insert("Leb128I".to_string(), SadLeb128::from_yaml);
         jump_table.insert("other".to_string(), SadLeaf::from_yaml);
         jump_table
     };
@@ -60,18 +250,134 @@ lazy_static! {
             .unwrap();
 }
 
-/// Implements Node for low level scalar types
+/// Gets the optional 'size_type' of a length prefix or tag
+fn size_type_from_yaml(in_yaml: &Yaml, default: &str) -> SadTreeResult<String> {
+    unsigned_type_from_yaml(in_yaml, SAD_YAML_SIZE_TYPE, default)
+}
+
+/// Gets an optional unsigned integer type declared under 'key'
+fn unsigned_type_from_yaml(in_yaml: &Yaml, key: &str, default: &str) -> SadTreeResult<String> {
+    match in_yaml[key].as_str() {
+        Some(st @ ("U8" | "U16" | "U32" | "U64")) => Ok(String::from(st)),
+        Some(_) => Err(SadTreeError::ExpectedLengthSchemaType),
+        None => Ok(String::from(default)),
+    }
+}
+
+/// Reads a length prefix or tag of 'size_type' from the data, failing
+/// if fewer bytes than its width remain
+fn read_size(size_type: &String, cursor: &mut SadCursor) -> SadTreeResult<usize> {
+    let width = size_type_width(size_type);
+    if width > cursor.data.len() {
+        return Err(SadTreeError::ReadPastEnd(cursor.position(), width));
+    }
+    Ok(to_usize_for(&deser_value_for(size_type, &mut cursor.data)).unwrap())
+}
+
+/// The length prefix or tag of 'size_type' at the start of 'data',
+/// None if fewer bytes than its width remain
+fn peek_size(size_type: &str, data: &[u8]) -> Option<usize> {
+    let mut prefix = data.get(..size_type_width(size_type))?;
+    to_usize_for(&deser_value_for(&size_type.to_string(), &mut prefix))
+}
+
+/// Byte width of an unsigned 'size_type'
+fn size_type_width(size_type: &str) -> usize {
+    match size_type {
+        "U8" => 1,
+        "U16" => 2,
+        "U32" => 4,
+        _ => 8,
+    }
+}
+
+/// Bytes a scalar of 'sad_value_type' occupies at the start of 'data',
+/// including the length prefix of a String
+fn scalar_len(sad_value_type: &str, data: &[u8]) -> usize {
+    match sad_value_type {
+        "Bool" | "U8" | "I8" => 1,
+        "U16" | "I16" => 2,
+        "U32" | "I32" | "F32" => 4,
+        "U64" | "I64" | "F64" => 8,
+        "U128" | "I128" => 16,
+        "PublicKey" => 32,
+        "String" => match data.get(..4) {
+            Some(prefix) => 4 + u32::from_le_bytes(prefix.try_into().unwrap()) as usize,
+            None => 4,
+        },
+        _ => 0,
+    }
+}
+
+/// Gets the optional 'max_len' guard of a length prefixed node
+fn max_len_from_yaml(in_yaml: &Yaml) -> SadTreeResult<Option<usize>> {
+    match &in_yaml[SAD_YAML_MAX_LEN] {
+        Yaml::BadValue => Ok(None),
+        Yaml::Integer(max_len) if *max_len >= 0 => Ok(Some(*max_len as usize)),
+        _ => Err(SadTreeError::ExpectedMaxLen),
+    }
+}
+
+/// Reads a length prefix of 'size_type', failing if fewer bytes than its
+/// width remain or if it exceeds 'max_len' before anything of that length is read
+fn read_len(
+    size_type: &String,
+    cursor: &mut SadCursor,
+    max_len: Option<usize>,
+) -> SadTreeResult<usize> {
+    let len = read_size(size_type, cursor)?;
+    match max_len {
+        Some(max_len) if len > max_len => Err(SadTreeError::LengthExceedsMax(len, max_len)),
+        _ => Ok(len),
+    }
+}
+
+/// Parses the packaged fragment 'name' in place of the Fragment
+fn parse_fragment(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+    match in_yaml[SAD_YAML_NAME].as_str() {
+        Some(name) => match fragment_descriptor(name) {
+            Some(fragment) => parse(&fragment),
+            None => Err(SadTreeError::UnknownFragment(name.to_string())),
+        },
+        None => Err(SadTreeError::ExpectedFragmentName),
+    }
+}
+
+/// Parses each entry of a YAML array into Nodes
+fn parse_array(in_yaml: &Yaml, err: SadTreeError) -> SadTreeResult<Vec<Box<dyn Node>>> {
+    match in_yaml {
+        Yaml::Array(lst) => {
+            let mut array = Vec::<Box<dyn Node>>::new();
+            for hl in lst {
+                array.push(parse(hl)?)
+            }
+            Ok(array)
+        }
+        _ => Err(err),
+    }
+}
+
+/// Implements Node for low level scalar types, a String
+/// may declare 'max_len' to guard its length prefix
 #[derive(Debug)]
 pub struct SadLeaf {
     sad_value_type: String,
+    max_len: Option<usize>,
 }
 
 impl SadLeaf {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
         let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
-        if is_sadvalue_type(in_str) {
+        if !in_yaml[SAD_YAML_SCALE_NUMERATOR].is_badvalue()
+            || !in_yaml[SAD_YAML_SCALE_DENOMINATOR].is_badvalue()
+        {
+            SadScaled::from_yaml(in_yaml)
+        } else if in_str == "String" && !in_yaml[SAD_YAML_SIZE_TYPE].is_badvalue() {
+            SadPrefixedString::from_yaml(in_yaml)
+        } else if is_sadvalue_type(in_str) {
             Ok(Box::new(SadLeaf {
                 sad_value_type: String::from(in_str),
+                max_len: max_len_from_yaml(in_yaml)?,
             }))
         } else {
             Err(SadTreeError::UnknownType(String::from(in_str)))
@@ -84,8 +390,251 @@ impl Node for SadLeaf {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        collection.push(deser_value_for(self.decl_type(), data));
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        if self.max_len.is_some() && self.sad_value_type == "String" {
+            // Only the length prefix is checked, the String is read below
+            let position = cursor.position();
+            read_len(&String::from(SAD_DEFAULT_SIZE_TYPE), cursor, self.max_len)?;
+            cursor.seek(position)?;
+        }
+        let len = scalar_len(&self.sad_value_type, cursor.data);
+        if len > cursor.data.len() {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), len));
+        }
+        collection.push(deser_value_for(self.decl_type(), &mut cursor.data));
+        Ok(())
+    }
+}
+
+/// Implements Node for a String with a declared 'size_type' length prefix,
+/// one of the unsigned types or ShortU16 (Solana's compact-u16, 1 to 3 bytes)
+#[derive(Debug)]
+pub struct SadPrefixedString {
+    sad_value_type: String,
+    size_type: String,
+    max_len: Option<usize>,
+}
+
+impl SadPrefixedString {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let size_type = match in_yaml[SAD_YAML_SIZE_TYPE].as_str() {
+            Some(SAD_SHORT_U16) => String::from(SAD_SHORT_U16),
+            _ => size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+        };
+        Ok(Box::new(SadPrefixedString {
+            sad_value_type: String::from(in_yaml[SAD_YAML_TYPE].as_str().unwrap()),
+            size_type,
+            max_len: max_len_from_yaml(in_yaml)?,
+        }))
+    }
+
+    /// Reads a compact-u16: 7 bit groups, low order first, while the
+    /// continuation bit is set. Like Solana's short_vec, fails on values
+    /// beyond 16 bits and on encodings longer than needed
+    fn read_short_u16(cursor: &mut SadCursor) -> SadTreeResult<usize> {
+        let mut value = 0usize;
+        for index in 0..3 {
+            let byte = match cursor.data.get(index) {
+                Some(byte) => *byte,
+                None => return Err(SadTreeError::ReadPastEnd(cursor.position(), index + 1)),
+            };
+            value |= ((byte & 0x7f) as usize) << (7 * index);
+            if byte & 0x80 == 0 {
+                if (index > 0 && byte == 0) || value > u16::MAX as usize {
+                    return Err(SadTreeError::InvalidShortU16);
+                }
+                cursor.data = &cursor.data[index + 1..];
+                return Ok(value);
+            }
+        }
+        Err(SadTreeError::InvalidShortU16)
+    }
+}
+
+impl Node for SadPrefixedString {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let len = if self.size_type == SAD_SHORT_U16 {
+            let len = SadPrefixedString::read_short_u16(cursor)?;
+            match self.max_len {
+                Some(max_len) if len > max_len => {
+                    return Err(SadTreeError::LengthExceedsMax(len, max_len))
+                }
+                _ => len,
+            }
+        } else {
+            read_len(&self.size_type, cursor, self.max_len)?
+        };
+        let bytes = match cursor.data.get(..len) {
+            Some(bytes) => bytes,
+            None => return Err(SadTreeError::ReadPastEnd(cursor.position(), len)),
+        };
+        let string = std::str::from_utf8(bytes)
+            .map_err(|_| SadTreeError::InvalidString(String::from_utf8_lossy(bytes).to_string()))?;
+        collection.push(SadValue::String(string.to_string()));
+        cursor.data = &cursor.data[len..];
+        Ok(())
+    }
+}
+
+/// Implements Node for LEB128 variable length integers, unsigned (Leb128U)
+/// decoding to U64 and signed (Leb128I) decoding to I64
+#[derive(Debug)]
+pub struct SadLeb128 {
+    sad_value_type: String,
+}
+
+impl SadLeb128 {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadLeb128 {
+            sad_value_type: String::from(in_str),
+        }))
+    }
+
+    /// Reads 7 bit groups, low order first, while the continuation bit is set
+    /// returning the accumulated value and the count of bits read
+    fn read(data: &mut &[u8], signed: bool) -> SadTreeResult<(u64, u32)> {
+        let mut value = 0u64;
+        for (index, byte) in data.iter().take(SAD_LEB128_MAX_BYTES).enumerate() {
+            let shift = 7 * index as u32;
+            // The 10th byte only holds bit 63, the rest must be zero
+            // or, for signed, the sign extension of bit 63
+            if index == SAD_LEB128_MAX_BYTES - 1
+                && !matches!((byte, signed), (0x00 | 0x01, false) | (0x00 | 0x7f, true))
+            {
+                return Err(SadTreeError::Leb128Overflow);
+            }
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                *data = &data[index + 1..];
+                return Ok((value, shift + 7));
+            }
+        }
+        if data.len() < SAD_LEB128_MAX_BYTES {
+            Err(SadTreeError::Leb128Truncated)
+        } else {
+            Err(SadTreeError::Leb128Overflow)
+        }
+    }
+}
+
+impl Node for SadLeb128 {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let position = cursor.position();
+        let (value, bits) =
+            match SadLeb128::read(&mut cursor.data, self.sad_value_type == "Leb128I") {
+                // Cut off by truncation the value is unavailable rather than malformed
+                Err(SadTreeError::Leb128Truncated) if cursor.truncated => {
+                    return Err(SadTreeError::ReadPastEnd(position, cursor.data.len() + 1))
+                }
+                read => read?,
+            };
+        collection.push(match self.sad_value_type.as_str() {
+            "Leb128I" if bits < 64 && value & (1 << (bits - 1)) != 0 => {
+                SadValue::I64((value | (u64::MAX << bits)) as i64)
+            }
+            "Leb128I" => SadValue::I64(value as i64),
+            _ => SadValue::U64(value),
+        });
+        Ok(())
+    }
+}
+
+/// Implements NodeWithChildren for a numeric scalar declared with
+/// 'scale_numerator' and/or 'scale_denominator', keeping the raw value
+/// alongside the scaled value rendered as a string
+#[derive(Debug)]
+pub struct SadScaled {
+    sad_value_type: String,
+    numerator: i128,
+    denominator: i128,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadScaled {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let factor = |key: &str| match &in_yaml[key] {
+            Yaml::BadValue => Ok(1i128),
+            Yaml::Integer(v) if *v != 0 => Ok(*v as i128),
+            _ => Err(SadTreeError::ExpectedScaleFactor),
+        };
+        let numerator = factor(SAD_YAML_SCALE_NUMERATOR)?;
+        let denominator = factor(SAD_YAML_SCALE_DENOMINATOR)?;
+        match in_str {
+            "U8" | "U16" | "U32" | "U64" | "U128" | "I8" | "I16" | "I32" | "I64" | "I128"
+            | "F32" | "F64" => Ok(Box::new(SadScaled {
+                sad_value_type: String::from(SAD_SCALED),
+                numerator,
+                denominator,
+                children: vec![Box::new(SadLeaf {
+                    sad_value_type: String::from(in_str),
+                    max_len: None,
+                })],
+            })),
+            _ => Err(SadTreeError::ExpectedScaleNumeric(String::from(in_str))),
+        }
+    }
+
+    /// Renders raw * numerator / denominator, exactly for integers
+    /// (up to SAD_SCALE_MAX_FRACTION_DIGITS) and as f64 otherwise
+    fn scale(&self, raw: &SadValue) -> String {
+        match to_i128_for(raw).and_then(|v| v.checked_mul(self.numerator)) {
+            Some(product) => {
+                let negative = (product < 0) != (self.denominator < 0) && product != 0;
+                let (product, denominator) =
+                    (product.unsigned_abs(), self.denominator.unsigned_abs());
+                let mut rendered = format!(
+                    "{}{}",
+                    if negative { "-" } else { "" },
+                    product / denominator
+                );
+                let mut remainder = product % denominator;
+                if remainder != 0 {
+                    rendered.push('.');
+                    for _ in 0..SAD_SCALE_MAX_FRACTION_DIGITS {
+                        if remainder == 0 {
+                            break;
+                        }
+                        remainder *= 10;
+                        rendered.push_str(&(remainder / denominator).to_string());
+                        remainder %= denominator;
+                    }
+                }
+                rendered
+            }
+            None => (to_f64_for(raw).unwrap() * self.numerator as f64 / self.denominator as f64)
+                .to_string(),
+        }
+    }
+}
+
+impl Node for SadScaled {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut raw = Vec::<SadValue>::new();
+        self.children[0].deser(cursor, &mut raw)?;
+        let scaled = SadValue::String(self.scale(&raw[0]));
+        raw.push(scaled);
+        collection.push(SadValue::Tuple(raw));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadScaled {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
     }
 }
 
@@ -97,7 +646,9 @@ pub struct SadPublicKey {
 impl SadPublicKey {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
         let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
-        if is_sadvalue_type(in_str) {
+        if !in_yaml[SAD_YAML_PDA_CHECK].is_badvalue() {
+            SadPdaCheck::from_yaml(in_yaml)
+        } else if is_sadvalue_type(in_str) {
             Ok(Box::new(SadPublicKey {
                 sad_value_type: String::from(in_str),
             }))
@@ -112,249 +663,1575 @@ impl Node for SadPublicKey {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        collection.push(deser_value_for(self.decl_type(), data));
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let len = scalar_len("PublicKey", cursor.data);
+        if len > cursor.data.len() {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), len));
+        }
+        collection.push(deser_value_for(self.decl_type(), &mut cursor.data));
+        Ok(())
     }
 }
 
-/// Implements NodeWithChildren for SadStructure Named Fields
+/// A PDA seed, literal bytes or a Data Section decoded before the PDA
 #[derive(Debug)]
-pub struct SadNamedField {
-    sad_field_name: String,
+enum SadSeed {
+    Bytes(Vec<u8>),
+    Field(String),
+}
+
+/// Implements NodeWithChildren for a PublicKey declared with 'pda_check',
+/// verifying it is the PDA of 'program' (the account owner by default)
+/// for 'seeds'. The key is output with whether it verified
+#[derive(Debug)]
+pub struct SadPdaCheck {
     sad_value_type: String,
+    program: Option<Pubkey>,
+    seeds: Vec<SadSeed>,
     children: Vec<Box<dyn Node>>,
 }
 
-impl SadNamedField {
+impl SadPdaCheck {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
-        let desc = &in_yaml[SAD_YAML_DESCRIPTOR];
-        let in_name = desc[SAD_YAML_NAME].as_str().unwrap();
-        let mut array = Vec::<Box<dyn Node>>::new();
-        let contains = &desc["contains"];
-        array.push(parse(contains)?);
-        Ok(Box::new(SadNamedField {
-            sad_field_name: String::from(in_name),
-            sad_value_type: String::from(SAD_NAMED_FIELD),
-            children: array,
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let check = &in_yaml[SAD_YAML_PDA_CHECK];
+        let program = match check[SAD_YAML_PROGRAM].as_str() {
+            None | Some(SAD_YAML_OWNER) => None,
+            Some(program) => {
+                Some(Pubkey::from_str(program).map_err(|_| SadTreeError::ExpectedPdaProgram)?)
+            }
+        };
+        let mut seeds = Vec::<SadSeed>::new();
+        match &check[SAD_YAML_SEEDS] {
+            Yaml::Array(lst) => {
+                for hl in lst {
+                    seeds.push(match (&hl["literal"], &hl["bytes"], &hl["field"]) {
+                        (Yaml::String(literal), Yaml::BadValue, Yaml::BadValue) => {
+                            SadSeed::Bytes(literal.as_bytes().to_vec())
+                        }
+                        (Yaml::BadValue, Yaml::Array(bytes), Yaml::BadValue) => SadSeed::Bytes(
+                            bytes
+                                .iter()
+                                .map(|b| b.as_i64().and_then(|b| u8::try_from(b).ok()))
+                                .collect::<Option<Vec<u8>>>()
+                                .ok_or(SadTreeError::ExpectedPdaSeeds)?,
+                        ),
+                        (Yaml::BadValue, Yaml::BadValue, Yaml::String(field)) => {
+                            SadSeed::Field(field.clone())
+                        }
+                        _ => return Err(SadTreeError::ExpectedPdaSeeds),
+                    })
+                }
+            }
+            _ => return Err(SadTreeError::ExpectedPdaSeeds),
+        }
+        Ok(Box::new(SadPdaCheck {
+            sad_value_type: String::from(SAD_PDA_CHECK),
+            program,
+            seeds,
+            children: vec![Box::new(SadPublicKey {
+                sad_value_type: String::from(in_str),
+            })],
+        }))
+    }
+
+    /// Whether 'key' is the PDA for the seeds, with the bump either
+    /// among the seeds or the canonical bump
+    fn verify(&self, cursor: &SadCursor, key: &Pubkey) -> SadTreeResult<bool> {
+        let program = match (&self.program, cursor.owner()) {
+            (Some(program), _) | (None, Some(program)) => program,
+            (None, None) => return Err(SadTreeError::OwnerRequired),
+        };
+        let mut seeds = Vec::<Vec<u8>>::new();
+        for seed in &self.seeds {
+            seeds.push(match seed {
+                SadSeed::Bytes(bytes) => bytes.clone(),
+                SadSeed::Field(name) => cursor
+                    .field(name)
+                    .and_then(to_seed_bytes_for)
+                    .ok_or_else(|| SadTreeError::InvalidSeedField(name.clone()))?,
+            })
+        }
+        let seeds: Vec<&[u8]> = seeds.iter().map(|s| s.as_slice()).collect();
+        Ok(
+            Pubkey::create_program_address(&seeds, program).ok() == Some(*key)
+                || Pubkey::find_program_address(&seeds, program).0 == *key,
+        )
+    }
+}
+
+impl Node for SadPdaCheck {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut checked = Vec::<SadValue>::new();
+        self.children[0].deser(cursor, &mut checked)?;
+        let valid = match &checked[0] {
+            SadValue::PublicKey(key) => self.verify(cursor, key)?,
+            _ => unreachable!(),
+        };
+        checked.push(SadValue::Bool(valid));
+        collection.push(SadValue::Tuple(checked));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadPdaCheck {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Checksum algorithms, Sha256 keeps the leading bytes of the digest
+#[derive(Debug)]
+enum SadChecksumAlgorithm {
+    Crc32,
+    Sha256(usize),
+}
+
+/// Implements NodeWithChildren for a checksum of the account data from 'start'
+/// (0 by default) up to 'end' (the checksum's own position by default), output
+/// with whether it matches the data
+#[derive(Debug)]
+pub struct SadChecksum {
+    sad_value_type: String,
+    algorithm: SadChecksumAlgorithm,
+    start: usize,
+    end: Option<usize>,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadChecksum {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let (algorithm, leaf) = match (
+            in_yaml[SAD_YAML_ALGORITHM].as_str(),
+            &in_yaml[SAD_YAML_SIZE],
+        ) {
+            (Some("CRC32"), Yaml::BadValue) => (SadChecksumAlgorithm::Crc32, "U32"),
+            (Some("Sha256"), Yaml::BadValue) => (SadChecksumAlgorithm::Sha256(32), "U8"),
+            (Some("Sha256"), Yaml::Integer(size)) if (1..=32).contains(size) => {
+                (SadChecksumAlgorithm::Sha256(*size as usize), "U8")
+            }
+            _ => return Err(SadTreeError::ExpectedChecksum),
+        };
+        let offset = |key: &str| match &in_yaml[key] {
+            Yaml::BadValue => Ok(None),
+            Yaml::Integer(offset) if *offset >= 0 => Ok(Some(*offset as usize)),
+            _ => Err(SadTreeError::ExpectedChecksum),
+        };
+        Ok(Box::new(SadChecksum {
+            sad_value_type: String::from(in_str),
+            algorithm,
+            start: offset(SAD_YAML_START)?.unwrap_or(0),
+            end: offset(SAD_YAML_END)?,
+            children: vec![Box::new(SadLeaf {
+                sad_value_type: String::from(leaf),
+                max_len: None,
+            })],
+        }))
+    }
+}
+
+impl Node for SadChecksum {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let end = self.end.unwrap_or_else(|| cursor.position());
+        let covered = match cursor.base.get(self.start..end) {
+            Some(covered) => covered,
+            None => {
+                return Err(SadTreeError::ChecksumRangeOutOfBounds(
+                    self.start,
+                    end,
+                    cursor.data_len(),
+                ))
+            }
+        };
+        let width = match self.algorithm {
+            SadChecksumAlgorithm::Crc32 => 4,
+            SadChecksumAlgorithm::Sha256(size) => size,
+        };
+        if cursor.data.len() < width {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), width));
+        }
+        let (stored, valid) = match self.algorithm {
+            SadChecksumAlgorithm::Crc32 => {
+                let stored = deser_value_for(&String::from("U32"), &mut cursor.data);
+                let valid = to_usize_for(&stored) == Some(crc32fast::hash(covered) as usize);
+                (stored, valid)
+            }
+            SadChecksumAlgorithm::Sha256(size) => {
+                let (stored, rest) = cursor.data.split_at(size);
+                cursor.data = rest;
+                let valid = hashv(&[covered]).to_bytes()[..size] == *stored;
+                (
+                    SadValue::Vec(stored.iter().map(|b| SadValue::U8(*b)).collect()),
+                    valid,
+                )
+            }
+        };
+        collection.push(SadValue::Tuple(vec![stored, SadValue::Bool(valid)]));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadChecksum {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements Node for Seek, moving the read position to the absolute 'offset'
+/// plus the value of the integer 'field' decoded before it, and Restore, returning
+/// to the position before the latest Seek. Both output the position moved to
+#[derive(Debug)]
+pub struct SadSeek {
+    sad_value_type: String,
+    offset: usize,
+    field: Option<String>,
+}
+
+impl SadSeek {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let (offset, field) = if in_str == "Restore" {
+            (0, None)
+        } else {
+            match (&in_yaml[SAD_YAML_OFFSET], &in_yaml[SAD_YAML_FIELD]) {
+                (Yaml::BadValue, Yaml::BadValue) => return Err(SadTreeError::ExpectedSeekOffset),
+                (Yaml::Integer(offset), Yaml::String(field)) if *offset >= 0 => {
+                    (*offset as usize, Some(field.clone()))
+                }
+                (Yaml::Integer(offset), Yaml::BadValue) if *offset >= 0 => (*offset as usize, None),
+                (Yaml::BadValue, Yaml::String(field)) => (0, Some(field.clone())),
+                _ => return Err(SadTreeError::ExpectedSeekOffset),
+            }
+        };
+        Ok(Box::new(SadSeek {
+            sad_value_type: String::from(in_str),
+            offset,
+            field,
         }))
     }
+}
+
+impl Node for SadSeek {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let position = if self.sad_value_type == "Restore" {
+            let position = cursor.saved.pop().ok_or(SadTreeError::RestoreWithoutSeek)?;
+            cursor.seek(position)?;
+            position
+        } else {
+            let base = match &self.field {
+                Some(name) => cursor
+                    .field(name)
+                    .and_then(to_usize_for)
+                    .ok_or_else(|| SadTreeError::InvalidSeekField(name.clone()))?,
+                None => 0,
+            };
+            let position = base + self.offset;
+            let from = cursor.position();
+            cursor.seek(position)?;
+            cursor.saved.push(from);
+            position
+        };
+        collection.push(SadValue::U64(position as u64));
+        Ok(())
+    }
+}
+
+/// Implements NodeWithChildren for Pointer, an offset of 'size_type' locating
+/// the contained descriptor elsewhere in the data. The offset is absolute or, when
+/// 'relative', a signed offset from the position of the pointer itself. The cursor
+/// returns to just after the offset once the target is decoded
+#[derive(Debug)]
+pub struct SadPointer {
+    sad_value_type: String,
+    size_type: String,
+    relative: bool,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadPointer {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let children = parse_array(
+            &in_yaml[SAD_YAML_CONTAINS],
+            SadTreeError::ExpectedPointerContains,
+        )?;
+        if children.len() != 1 {
+            return Err(SadTreeError::ExpectedPointerContains);
+        }
+        Ok(Box::new(SadPointer {
+            sad_value_type: String::from(in_str),
+            size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+            relative: match in_yaml[SAD_YAML_RELATIVE] {
+                Yaml::BadValue => false,
+                Yaml::Boolean(relative) => relative,
+                _ => return Err(SadTreeError::ExpectedPointerRelative),
+            },
+            children,
+        }))
+    }
+}
+
+impl Node for SadPointer {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let width = size_type_width(&self.size_type);
+        let from = cursor.position();
+        let offset = read_size(&self.size_type, cursor)? as i128;
+        let target = if self.relative {
+            // Sign extend the offset from its width
+            let bits = width as u32 * 8;
+            let offset = if offset >> (bits - 1) & 1 == 1 {
+                offset - (1i128 << bits)
+            } else {
+                offset
+            };
+            from as i128 + offset
+        } else {
+            offset
+        };
+        let target = match usize::try_from(target) {
+            Ok(target) if target < cursor.data_len() => target,
+            _ => return Err(SadTreeError::PointerOutOfRange(target, cursor.data_len())),
+        };
+        if cursor.pointers.contains(&target) {
+            return Err(SadTreeError::PointerCycle(target));
+        }
+        let restore = cursor.position();
+        cursor.pointers.push(target);
+        cursor.seek(target)?;
+        self.children[0].deser(cursor, collection)?;
+        cursor.pointers.pop();
+        cursor.seek(restore)
+    }
+}
+
+impl NodeWithChildren for SadPointer {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for FollowRef, a PublicKey whose account is fetched
+/// and decoded with the contained descriptor. References are followed one level
+/// deep, those within a followed account, or whose account is unavailable, are
+/// output without the account contents
+#[derive(Debug)]
+pub struct SadFollowRef {
+    sad_value_type: String,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadFollowRef {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let children = parse_array(
+            &in_yaml[SAD_YAML_CONTAINS],
+            SadTreeError::ExpectedFollowRefContains,
+        )?;
+        if children.len() != 1 {
+            return Err(SadTreeError::ExpectedFollowRefContains);
+        }
+        Ok(Box::new(SadFollowRef {
+            sad_value_type: String::from(in_str),
+            children,
+        }))
+    }
+}
+
+impl Node for SadFollowRef {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let len = scalar_len("PublicKey", cursor.data);
+        if cursor.data.len() < len {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), len));
+        }
+        let key = deser_value_for(&String::from("PublicKey"), &mut cursor.data);
+        let mut contents = Vec::<SadValue>::new();
+        if let SadValue::PublicKey(pubkey) = &key {
+            // The followed account is decoded without a resolver, stopping further references
+            if let Some(account) = cursor.resolver.and_then(|r| r.resolve(pubkey)) {
+                let mut followed = SadCursor::new(&account.data).with_owner(&account.owner);
+                self.children[0]
+                    .deser(&mut followed, &mut contents)
+                    .map_err(|e| {
+                        SadTreeError::FollowRefFailed(pubkey.to_string(), e.to_string())
+                    })?;
+            }
+        }
+        collection.push(SadValue::Tuple(vec![key, SadValue::Option(contents)]));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadFollowRef {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements Node for a fixed 'size' buffer holding a null terminated UTF-8
+/// string, the string ends at the first null (or fills the buffer without one)
+/// and the padding after it is skipped
+#[derive(Debug)]
+pub struct SadCString {
+    sad_value_type: String,
+    size: usize,
+}
+
+impl SadCString {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        match in_yaml[SAD_YAML_SIZE] {
+            Yaml::Integer(size) if size > 0 => Ok(Box::new(SadCString {
+                sad_value_type: String::from(in_str),
+                size: size as usize,
+            })),
+            _ => Err(SadTreeError::ExpectedCStringSize),
+        }
+    }
+}
+
+impl Node for SadCString {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let buffer = match cursor.data.get(..self.size) {
+            Some(buffer) => buffer,
+            None => return Err(SadTreeError::ReadPastEnd(cursor.position(), self.size)),
+        };
+        let end = buffer.iter().position(|b| *b == 0).unwrap_or(self.size);
+        let string = std::str::from_utf8(&buffer[..end]).map_err(|_| {
+            SadTreeError::InvalidCString(String::from_utf8_lossy(&buffer[..end]).to_string())
+        })?;
+        collection.push(SadValue::String(string.to_string()));
+        cursor.data = &cursor.data[self.size..];
+        Ok(())
+    }
+}
+
+/// Implements Node for a UnixTimestamp (I64 seconds) rendered as UTC
+/// ISO 8601, e.g. "2023-11-14T22:13:20Z"
+#[derive(Debug)]
+pub struct SadTimestamp {
+    sad_value_type: String,
+}
+
+impl SadTimestamp {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadTimestamp {
+            sad_value_type: String::from(in_str),
+        }))
+    }
+
+    /// Proleptic Gregorian date and time of 'secs' since the epoch
+    fn utc(secs: i64) -> String {
+        let (days, secs) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+        // Days to civil date, with eras of 400 years starting March 1st
+        let z = days + 719_468;
+        let (era, doe) = (z.div_euclid(146_097), z.rem_euclid(146_097));
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs % 3600 / 60,
+            secs % 60
+        )
+    }
+}
+
+impl Node for SadTimestamp {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        if cursor.data.len() < 8 {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), 8));
+        }
+        let (secs, rest) = cursor.data.split_at(8);
+        cursor.data = rest;
+        collection.push(SadValue::String(SadTimestamp::utc(i64::from_le_bytes(
+            secs.try_into().unwrap(),
+        ))));
+        Ok(())
+    }
+}
+
+/// Implements Node for UTF-8 JSON text, of 'size' bytes or the rest of the
+/// data by default, with trailing null padding ignored. The parsed JSON is
+/// embedded in the output
+#[derive(Debug)]
+pub struct SadJsonText {
+    sad_value_type: String,
+    size: Option<usize>,
+}
+
+impl SadJsonText {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let size = match in_yaml[SAD_YAML_SIZE] {
+            Yaml::BadValue => None,
+            Yaml::Integer(size) if size > 0 => Some(size as usize),
+            _ => return Err(SadTreeError::ExpectedJsonTextSize),
+        };
+        Ok(Box::new(SadJsonText {
+            sad_value_type: String::from(in_str),
+            size,
+        }))
+    }
+}
+
+impl Node for SadJsonText {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let size = self.size.unwrap_or(cursor.data.len());
+        let raw = match cursor.data.get(..size) {
+            Some(raw) => raw,
+            None => return Err(SadTreeError::ReadPastEnd(cursor.position(), size)),
+        };
+        let end = raw.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        let text = std::str::from_utf8(&raw[..end])
+            .map_err(|e| SadTreeError::InvalidJsonText(e.to_string()))?;
+        serde_json::from_str::<Value>(text)
+            .map_err(|e| SadTreeError::InvalidJsonText(e.to_string()))?;
+        collection.push(SadValue::String(text.to_string()));
+        cursor.data = &cursor.data[size..];
+        Ok(())
+    }
+}
+
+/// Implements NodeWithChildren for a fixed 'size' ASCII flag string (e.g. "RW-"),
+/// output as the string or, when 'flags' names positions, as an object of named
+/// booleans set when the character is not the 'unset' character ('-' by default)
+#[derive(Debug)]
+pub struct SadFlagString {
+    sad_value_type: String,
+    size: usize,
+    unset: u8,
+    flags: Vec<Option<String>>,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadFlagString {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let size = match in_yaml[SAD_YAML_SIZE] {
+            Yaml::Integer(size) if size > 0 => size as usize,
+            _ => return Err(SadTreeError::ExpectedFlagString),
+        };
+        let unset = match in_yaml[SAD_YAML_UNSET].as_str() {
+            None => b'-',
+            Some(unset) if unset.len() == 1 && unset.is_ascii() => unset.as_bytes()[0],
+            Some(_) => return Err(SadTreeError::ExpectedFlagString),
+        };
+        let flags = match &in_yaml[SAD_YAML_FLAGS] {
+            Yaml::BadValue => Vec::new(),
+            Yaml::Array(lst) if lst.len() <= size => lst
+                .iter()
+                .map(|f| match f {
+                    Yaml::String(name) => Ok(Some(name.clone())),
+                    Yaml::Null => Ok(None),
+                    _ => Err(SadTreeError::ExpectedFlagString),
+                })
+                .collect::<SadTreeResult<Vec<Option<String>>>>()?,
+            _ => return Err(SadTreeError::ExpectedFlagString),
+        };
+        let children = flags
+            .iter()
+            .flatten()
+            .map(|name| -> Box<dyn Node> {
+                Box::new(SadNamedField {
+                    sad_field_name: name.clone(),
+                    sad_value_type: String::from("NamedField"),
+                    children: vec![Box::new(SadLeaf {
+                        sad_value_type: String::from("Bool"),
+                        max_len: None,
+                    })],
+                })
+            })
+            .collect();
+        Ok(Box::new(SadFlagString {
+            sad_value_type: String::from(in_str),
+            size,
+            unset,
+            flags,
+            children,
+        }))
+    }
+}
+
+impl Node for SadFlagString {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        if cursor.data.len() < self.size {
+            return Err(SadTreeError::ReadPastEnd(cursor.position(), self.size));
+        }
+        let (raw, rest) = cursor.data.split_at(self.size);
+        if !raw.is_ascii() {
+            return Err(SadTreeError::InvalidFlagString(
+                String::from_utf8_lossy(raw).to_string(),
+            ));
+        }
+        cursor.data = rest;
+        if self.flags.is_empty() {
+            collection.push(SadValue::String(String::from_utf8_lossy(raw).to_string()));
+        } else {
+            let mut coll = Vec::<SadValue>::new();
+            for (flag, byte) in self.flags.iter().zip(raw) {
+                if let Some(name) = flag {
+                    coll.push(SadValue::NamedField(vec![
+                        SadValue::String(name.clone()),
+                        SadValue::Bool(*byte != self.unset),
+                    ]));
+                }
+            }
+            collection.push(SadValue::CStruct(coll));
+        }
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadFlagString {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for SadStructure Named Fields
+#[derive(Debug)]
+pub struct SadNamedField {
+    sad_field_name: String,
+    sad_value_type: String,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadNamedField {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let desc = &in_yaml[SAD_YAML_DESCRIPTOR];
+        let in_name = desc[SAD_YAML_NAME].as_str().unwrap();
+        let mut array = Vec::<Box<dyn Node>>::new();
+        let contains = &desc["contains"];
+        array.push(parse(contains)?);
+        Ok(Box::new(SadNamedField {
+            sad_field_name: String::from(in_name),
+            sad_value_type: String::from(SAD_NAMED_FIELD),
+            children: array,
+        }))
+    }
+
+    fn name(&self) -> &String {
+        &self.sad_field_name
+    }
+}
+
+impl Node for SadNamedField {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut coll = Vec::<SadValue>::new();
+        coll.push(SadValue::String(self.name().clone()));
+        for c in &self.children {
+            c.deser(cursor, &mut coll)?
+        }
+        collection.push(SadValue::NamedField(coll));
+        Ok(())
+    }
+}
+impl NodeWithChildren for SadNamedField {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for HashMap
+#[derive(Debug)]
+pub struct SadHashMap {
+    sad_value_type: String,
+    size_type: String,
+    max_len: Option<usize>,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadHashMap {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadHashMap {
+            sad_value_type: String::from(in_str),
+            size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+            max_len: max_len_from_yaml(in_yaml)?,
+            children: parse_array(
+                &in_yaml[SAD_YAML_FIELDS],
+                SadTreeError::ExpectedHashMapFields,
+            )?,
+        }))
+    }
+}
+impl Node for SadHashMap {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut coll = Vec::<Vec<SadValue>>::new();
+        let count = read_len(&self.size_type, cursor, self.max_len)?;
+        for _ in 0..count {
+            let mut spare = Vec::<SadValue>::new();
+            for c in &self.children {
+                c.deser(cursor, &mut spare)?;
+            }
+            coll.push(spare);
+        }
+        collection.push(SadValue::HashMap(coll));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadHashMap {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for Structure (i.e. Rust struct)
+#[derive(Debug)]
+pub struct SadStructure {
+    sad_value_type: String,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadStructure {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadStructure {
+            sad_value_type: String::from(in_str),
+            children: parse_array(
+                &in_yaml[SAD_YAML_FIELDS],
+                SadTreeError::ExpectedCStructFields,
+            )?,
+        }))
+    }
+}
+impl Node for SadStructure {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut coll = Vec::<SadValue>::new();
+        for c in &self.children {
+            c.deser(cursor, &mut coll)?
+        }
+        collection.push(SadValue::CStruct(coll));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadStructure {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for Vector (i.e. Rust Vec), with 'byte_length'
+/// the prefix is the byte size of the elements rather than their count
+#[derive(Debug)]
+pub struct SadVector {
+    sad_value_type: String,
+    size_type: String,
+    max_len: Option<usize>,
+    byte_length: bool,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadVector {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let children = parse_array(
+            &in_yaml[SAD_YAML_CONTAINS],
+            SadTreeError::ExpectedVecContains,
+        )?;
+        Ok(Box::new(SadVector {
+            sad_value_type: String::from(in_str),
+            size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+            max_len: max_len_from_yaml(in_yaml)?,
+            byte_length: match in_yaml[SAD_YAML_BYTE_LENGTH] {
+                Yaml::BadValue => false,
+                Yaml::Boolean(byte_length) => byte_length,
+                _ => return Err(SadTreeError::ExpectedByteLength),
+            },
+            children,
+        }))
+    }
+
+    /// A TaggedVec is a Vec of Enum 'variants', the count is
+    /// read as 'size_type' and each variant tag as 'tag_type',
+    /// both defaulting to U8
+    fn tagged_from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadVector {
+            sad_value_type: String::from(in_str),
+            size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_TAG_TYPE)?,
+            max_len: max_len_from_yaml(in_yaml)?,
+            byte_length: false,
+            children: vec![SadEnum::with_tag_type(
+                in_yaml,
+                unsigned_type_from_yaml(in_yaml, SAD_YAML_TAG_TYPE, SAD_DEFAULT_TAG_TYPE)?,
+            )?],
+        }))
+    }
+}
+impl Node for SadVector {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        // let mut coll = Vec::<Vec<SadValue>>::new();
+        let count = read_len(&self.size_type, cursor, self.max_len)?;
+        let mut spare = Vec::<SadValue>::new();
+        if self.byte_length {
+            if count > cursor.data.len() {
+                return Err(SadTreeError::ReadPastEnd(cursor.position(), count));
+            }
+            // Elements are read until they consume exactly 'count' bytes
+            let start = cursor.position();
+            while cursor.position() < start + count {
+                let from = cursor.position();
+                for c in &self.children {
+                    c.deser(cursor, &mut spare)?;
+                }
+                // An element that consumes no bytes would never reach 'count'
+                if cursor.position() <= from {
+                    return Err(SadTreeError::ByteLengthNoProgress(from));
+                }
+            }
+            if cursor.position() != start + count {
+                return Err(SadTreeError::ByteLengthMismatch(
+                    count,
+                    cursor.position() - start,
+                ));
+            }
+        } else {
+            for _ in 0..count {
+                for c in &self.children {
+                    c.deser(cursor, &mut spare)?;
+                }
+            }
+        }
+        collection.push(SadValue::Vec(spare));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadVector {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for Tuple (i.e. Rust tuple)
+#[derive(Debug)]
+pub struct SadTuple {
+    sad_value_type: String,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadTuple {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadTuple {
+            sad_value_type: String::from(in_str),
+            children: parse_array(&in_yaml[SAD_YAML_FIELDS], SadTreeError::ExpectedTupleFields)?,
+        }))
+    }
+}
+impl Node for SadTuple {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut spare = Vec::<SadValue>::new();
+        for c in &self.children {
+            c.deser(cursor, &mut spare)?
+        }
+        collection.push(SadValue::Tuple(spare));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadTuple {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for fixed size Array (i.e. Rust [T; N])
+#[derive(Debug)]
+pub struct SadArray {
+    sad_value_type: String,
+    size: usize,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadArray {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        match in_yaml[SAD_YAML_SIZE].as_i64() {
+            Some(size) if size >= 0 => Ok(Box::new(SadArray {
+                sad_value_type: String::from(in_str),
+                size: size as usize,
+                children: parse_array(
+                    &in_yaml[SAD_YAML_CONTAINS],
+                    SadTreeError::ExpectedArraySize,
+                )?,
+            })),
+            _ => Err(SadTreeError::ExpectedArraySize),
+        }
+    }
+}
+
+impl Node for SadArray {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut spare = Vec::<SadValue>::new();
+        for _ in 0..self.size {
+            for c in &self.children {
+                c.deser(cursor, &mut spare)?;
+            }
+        }
+        collection.push(SadValue::Vec(spare));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadArray {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for run length encoded sections, a 'size_type'
+/// prefixed sequence of (count, value) runs expanded into a flat array
+#[derive(Debug)]
+pub struct SadRunLength {
+    sad_value_type: String,
+    size_type: String,
+    count_type: String,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadRunLength {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        Ok(Box::new(SadRunLength {
+            sad_value_type: String::from(in_str),
+            size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+            count_type: unsigned_type_from_yaml(
+                in_yaml,
+                SAD_YAML_COUNT_TYPE,
+                SAD_DEFAULT_SIZE_TYPE,
+            )?,
+            children: parse_array(
+                &in_yaml[SAD_YAML_CONTAINS],
+                SadTreeError::ExpectedRunLengthContains,
+            )?,
+        }))
+    }
+}
+
+impl Node for SadRunLength {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let runs = read_size(&self.size_type, cursor)?;
+        let mut spare = Vec::<SadValue>::new();
+        for _ in 0..runs {
+            let count = read_size(&self.count_type, cursor)?;
+            if count > SAD_RUN_LENGTH_MAX_ELEMENTS - spare.len() {
+                return Err(SadTreeError::RunLengthTooLarge(SAD_RUN_LENGTH_MAX_ELEMENTS));
+            }
+            let mut value = Vec::<SadValue>::new();
+            for c in &self.children {
+                c.deser(cursor, &mut value)?;
+            }
+            for _ in 0..count {
+                spare.extend(value.iter().cloned());
+            }
+        }
+        collection.push(SadValue::Vec(spare));
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadRunLength {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for a variable byte blob filling the data
+/// from the current position up to a fixed size trailer at the end
+#[derive(Debug)]
+pub struct SadFillBetween {
+    sad_value_type: String,
+    trailer_size: usize,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadFillBetween {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        match in_yaml[SAD_YAML_TRAILER_SIZE].as_i64() {
+            Some(size) if size >= 0 => Ok(Box::new(SadFillBetween {
+                sad_value_type: String::from(in_str),
+                trailer_size: size as usize,
+                children: vec![Box::new(SadLeaf {
+                    sad_value_type: String::from("U8"),
+                    max_len: None,
+                })],
+            })),
+            _ => Err(SadTreeError::ExpectedTrailerSize),
+        }
+    }
+}
+
+impl Node for SadFillBetween {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let data = &mut cursor.data;
+        if self.trailer_size > data.len() {
+            return Err(SadTreeError::TrailerExceedsData(
+                self.trailer_size,
+                data.len(),
+            ));
+        }
+        let (body, trailer) = data.split_at(data.len() - self.trailer_size);
+        collection.push(SadValue::Vec(
+            body.iter().map(|b| SadValue::U8(*b)).collect(),
+        ));
+        *data = trailer;
+        Ok(())
+    }
+}
+
+impl NodeWithChildren for SadFillBetween {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
+
+/// Implements NodeWithChildren for a blob located by an (offset, length) entry,
+/// each 'size_type', found 'trailer_offset' bytes before the end of the data.
+/// The blob is read from its absolute offset and the cursor is then restored
+#[derive(Debug)]
+pub struct SadTrailerRef {
+    sad_value_type: String,
+    size_type: String,
+    trailer_offset: usize,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadTrailerRef {
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        match in_yaml[SAD_YAML_TRAILER_OFFSET].as_i64() {
+            Some(offset) if offset > 0 => Ok(Box::new(SadTrailerRef {
+                sad_value_type: String::from(in_str),
+                size_type: size_type_from_yaml(in_yaml, SAD_DEFAULT_SIZE_TYPE)?,
+                trailer_offset: offset as usize,
+                children: vec![Box::new(SadLeaf {
+                    sad_value_type: String::from("U8"),
+                    max_len: None,
+                })],
+            })),
+            _ => Err(SadTreeError::ExpectedTrailerOffset),
+        }
+    }
+}
+
+impl Node for SadTrailerRef {
+    fn decl_type(&self) -> &String {
+        &self.sad_value_type
+    }
+
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let restore = cursor.position();
+        let data_len = cursor.data_len();
+        if self.trailer_offset > data_len {
+            return Err(SadTreeError::SeekOutOfRange(self.trailer_offset, data_len));
+        }
+        cursor.seek(data_len - self.trailer_offset)?;
+        let offset = read_size(&self.size_type, cursor)?;
+        let length = read_size(&self.size_type, cursor)?;
+        cursor.seek(offset)?;
+        let blob = match cursor.data.get(..length) {
+            Some(blob) => blob,
+            None => {
+                return Err(SadTreeError::SeekOutOfRange(
+                    offset.saturating_add(length),
+                    data_len,
+                ))
+            }
+        };
+        collection.push(SadValue::Vec(
+            blob.iter().map(|b| SadValue::U8(*b)).collect(),
+        ));
+        cursor.seek(restore)
+    }
+}
+
+impl NodeWithChildren for SadTrailerRef {
+    fn children(&self) -> &Vec<Box<dyn Node>> {
+        &self.children
+    }
+}
 
-    fn name(&self) -> &String {
-        &self.sad_field_name
+/// Implements NodeWithChildren for Option (i.e. Rust Option)
+/// The tag is a single byte, as borsh and bincode write it, unless 'size_type' is given
+#[derive(Debug)]
+pub struct SadOption {
+    sad_value_type: String,
+    size_type: String,
+    strict: bool,
+    auto: bool,
+    children: Vec<Box<dyn Node>>,
+}
+
+impl SadOption {
+    /// The 'mode' is borsh (default, tag of 'size_type'), bincode
+    /// (U8 tag) or auto (tag of 'size_type', diagnosing a tag of the
+    /// other width, including a U32 Some tag read as a U8). 'strict'
+    /// rejects tags other than 0/1 and defaults to true for bincode
+    /// and auto
+    fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let (size_type, strict, auto) = match in_yaml[SAD_YAML_MODE].as_str() {
+            None | Some("borsh") => (
+                size_type_from_yaml(in_yaml, SAD_DEFAULT_TAG_TYPE)?,
+                false,
+                false,
+            ),
+            Some("bincode") => (String::from(SAD_DEFAULT_TAG_TYPE), true, false),
+            Some("auto") => (
+                size_type_from_yaml(in_yaml, SAD_DEFAULT_TAG_TYPE)?,
+                true,
+                true,
+            ),
+            Some(mode) => return Err(SadTreeError::UnknownOptionMode(mode.to_string())),
+        };
+        Ok(Box::new(SadOption {
+            sad_value_type: String::from(in_str),
+            size_type,
+            strict: in_yaml[SAD_YAML_STRICT].as_bool().unwrap_or(strict),
+            auto,
+            children: parse_array(
+                &in_yaml[SAD_YAML_CONTAINS],
+                SadTreeError::ExpectedOptionContains,
+            )?,
+        }))
     }
 }
 
-impl Node for SadNamedField {
+impl Node for SadOption {
     fn decl_type(&self) -> &String {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        let mut coll = Vec::<SadValue>::new();
-        coll.push(SadValue::String(self.name().clone()));
-        for c in &self.children {
-            c.deser(data, &mut coll)
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut spare = Vec::<SadValue>::new();
+        let peek = cursor.data;
+        let tag = read_size(&self.size_type, cursor)?;
+        if self.auto {
+            // SPL COption uses a U32 tag where borsh uses a U8, a Some
+            // COption tag read as a U8 is 1 so the other width is compared too
+            let other = if self.size_type == "U8" { "U32" } else { "U8" };
+            let other_tag = peek_size(other, peek);
+            if (tag > 1 && other_tag.is_some_and(|t| t <= 1))
+                || (self.size_type == "U8" && tag == 1 && other_tag == Some(1))
+            {
+                return Err(SadTreeError::OptionTagWidthMismatch(
+                    tag,
+                    self.size_type.clone(),
+                    other.to_string(),
+                ));
+            }
         }
-        collection.push(SadValue::NamedField(coll));
+        if self.strict && tag > 1 {
+            return Err(SadTreeError::InvalidOptionTag(tag));
+        }
+        if tag != 0 {
+            for c in &self.children {
+                c.deser(cursor, &mut spare)?;
+            }
+        }
+        collection.push(SadValue::Option(spare));
+        Ok(())
     }
 }
-impl NodeWithChildren for SadNamedField {
+
+impl NodeWithChildren for SadOption {
     fn children(&self) -> &Vec<Box<dyn Node>> {
         &self.children
     }
 }
 
-/// Implements NodeWithChildren for HashMap
+/// Implements NodeWithChildren for an Enum Variant
 #[derive(Debug)]
-pub struct SadHashMap {
+pub struct SadVariant {
+    sad_variant_name: String,
     sad_value_type: String,
     children: Vec<Box<dyn Node>>,
 }
 
-impl SadHashMap {
+impl SadVariant {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
-        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
-        let mut array = Vec::<Box<dyn Node>>::new();
-        let fields = &in_yaml[SAD_YAML_FIELDS];
-        match fields {
-            Yaml::Array(lst) => {
-                for hl in lst {
-                    array.push(parse(hl).unwrap())
-                }
-                Ok(Box::new(SadHashMap {
-                    sad_value_type: String::from(in_str),
-                    children: array,
-                }))
-            }
-            _ => Err(SadTreeError::ExpectedHashMapFields),
+        match in_yaml[SAD_YAML_NAME].as_str() {
+            Some(in_name) => SadVariant::with_name(in_name, in_yaml),
+            None => Err(SadTreeError::ExpectedEnumVariants),
         }
     }
+
+    fn with_name(in_name: &str, in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
+        let children = match &in_yaml[SAD_YAML_FIELDS] {
+            Yaml::BadValue => Vec::<Box<dyn Node>>::new(),
+            fields => parse_array(fields, SadTreeError::ExpectedEnumVariants)?,
+        };
+        Ok(Box::new(SadVariant {
+            sad_variant_name: String::from(in_name),
+            sad_value_type: String::from(SAD_VARIANT),
+            children,
+        }))
+    }
+
+    fn name(&self) -> &String {
+        &self.sad_variant_name
+    }
 }
-impl Node for SadHashMap {
+
+impl Node for SadVariant {
     fn decl_type(&self) -> &String {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        let mut coll = Vec::<Vec<SadValue>>::new();
-        let count = u32::try_from_slice(&data[0..4]).unwrap();
-        *data = &data[4..];
-        for _ in 0..count {
-            let mut spare = Vec::<SadValue>::new();
-            for c in &self.children {
-                c.deser(data, &mut spare);
-            }
-            coll.push(spare);
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let mut coll = Vec::<SadValue>::new();
+        coll.push(SadValue::String(self.name().clone()));
+        for c in &self.children {
+            c.deser(cursor, &mut coll)?
         }
-        collection.push(SadValue::HashMap(coll))
+        collection.push(SadValue::Enum(coll));
+        Ok(())
     }
 }
 
-impl NodeWithChildren for SadHashMap {
+impl NodeWithChildren for SadVariant {
     fn children(&self) -> &Vec<Box<dyn Node>> {
         &self.children
     }
 }
 
-/// Implements NodeWithChildren for Structure (i.e. Rust struct)
+/// How an Enum identifies its variant
 #[derive(Debug)]
-pub struct SadStructure {
+enum SadEnumTag {
+    /// Position in 'variants'
+    Index,
+    /// Variant name as a 'size_type' length prefixed string
+    Name,
+    /// Variant name in a fixed size null padded buffer
+    FixedName(usize),
+}
+
+/// Implements NodeWithChildren for Enum (i.e. Rust enum)
+/// The variant tag is its position in 'variants' and is
+/// read as 'size_type', U8 for borsh or U32 for bincode,
+/// unless a String or CString 'discriminant' names it
+#[derive(Debug)]
+pub struct SadEnum {
     sad_value_type: String,
+    size_type: String,
+    tag: SadEnumTag,
     children: Vec<Box<dyn Node>>,
 }
 
-impl SadStructure {
+impl SadEnum {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
-        let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
-        let mut array = Vec::<Box<dyn Node>>::new();
-        let fields = &in_yaml[SAD_YAML_FIELDS];
+        let (tag, default_size_type) = match in_yaml[SAD_YAML_DISCRIMINANT].as_str() {
+            None if in_yaml[SAD_YAML_DISCRIMINANT].is_badvalue() => {
+                (SadEnumTag::Index, SAD_DEFAULT_TAG_TYPE)
+            }
+            Some("String") => (SadEnumTag::Name, SAD_DEFAULT_SIZE_TYPE),
+            Some("CString") => match in_yaml[SAD_YAML_SIZE].as_i64() {
+                Some(size) if size > 0 => {
+                    (SadEnumTag::FixedName(size as usize), SAD_DEFAULT_TAG_TYPE)
+                }
+                _ => return Err(SadTreeError::ExpectedEnumDiscriminant),
+            },
+            _ => return Err(SadTreeError::ExpectedEnumDiscriminant),
+        };
+        Ok(Box::new(SadEnum {
+            sad_value_type: String::from(SAD_ENUM),
+            size_type: size_type_from_yaml(in_yaml, default_size_type)?,
+            tag,
+            children: SadEnum::variants_from_yaml(in_yaml)?,
+        }))
+    }
 
-        match fields {
+    fn with_tag_type(in_yaml: &Yaml, tag_type: String) -> SadTreeResult<Box<dyn Node>> {
+        Ok(Box::new(SadEnum {
+            sad_value_type: String::from(SAD_ENUM),
+            size_type: tag_type,
+            tag: SadEnumTag::Index,
+            children: SadEnum::variants_from_yaml(in_yaml)?,
+        }))
+    }
+
+    fn variants_from_yaml(in_yaml: &Yaml) -> SadTreeResult<Vec<Box<dyn Node>>> {
+        let mut array = Vec::<Box<dyn Node>>::new();
+        match &in_yaml[SAD_YAML_VARIANTS] {
             Yaml::Array(lst) => {
                 for hl in lst {
-                    array.push(parse(hl).unwrap())
+                    array.push(SadVariant::from_yaml(hl)?)
                 }
-                Ok(Box::new(SadStructure {
-                    sad_value_type: String::from(in_str),
-                    children: array,
-                }))
+                Ok(array)
             }
-            _ => Err(SadTreeError::ExpectedCStructFields),
+            _ => Err(SadTreeError::ExpectedEnumVariants),
         }
     }
+
+    /// Reads a variant name discriminant of 'len' bytes, trailing nulls trimmed
+    fn read_name(cursor: &mut SadCursor, len: usize) -> SadTreeResult<String> {
+        let bytes = match cursor.data.get(..len) {
+            Some(bytes) => bytes,
+            None => return Err(SadTreeError::ReadPastEnd(cursor.position(), len)),
+        };
+        cursor.data = &cursor.data[len..];
+        let end = bytes.iter().rposition(|b| *b != 0).map_or(0, |p| p + 1);
+        Ok(String::from_utf8_lossy(&bytes[..end]).to_string())
+    }
 }
-impl Node for SadStructure {
+
+impl Node for SadEnum {
     fn decl_type(&self) -> &String {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        let mut coll = Vec::<SadValue>::new();
-        for c in &self.children {
-            c.deser(data, &mut coll)
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let name = match self.tag {
+            SadEnumTag::Index => {
+                let tag = read_size(&self.size_type, cursor)?;
+                return match self.children.get(tag) {
+                    Some(variant) => variant.deser(cursor, collection),
+                    None => Err(SadTreeError::UnknownEnumTag(tag)),
+                };
+            }
+            SadEnumTag::Name => {
+                let len = read_size(&self.size_type, cursor)?;
+                SadEnum::read_name(cursor, len)?
+            }
+            SadEnumTag::FixedName(size) => SadEnum::read_name(cursor, size)?,
+        };
+        match self
+            .children
+            .iter()
+            .find(|v| v.downcast_ref::<SadVariant>().unwrap().name() == &name)
+        {
+            Some(variant) => variant.deser(cursor, collection),
+            None => Err(SadTreeError::UnknownEnumName(name)),
         }
-        collection.push(SadValue::CStruct(coll))
     }
 }
 
-impl NodeWithChildren for SadStructure {
+impl NodeWithChildren for SadEnum {
     fn children(&self) -> &Vec<Box<dyn Node>> {
         &self.children
     }
 }
 
-/// Implements NodeWithChildren for Vector (i.e. Rust Vec)
+/// Implements NodeWithChildren for selecting a layout by the account owner,
+/// each of 'owners' is a variant with an 'owner' pubkey, an optional 'name'
+/// (defaulting to the owner) and 'fields'. Output is as for Enum
 #[derive(Debug)]
-pub struct SadVector {
+pub struct SadOwnerSwitch {
     sad_value_type: String,
+    owners: Vec<Pubkey>,
     children: Vec<Box<dyn Node>>,
 }
 
-impl SadVector {
+impl SadOwnerSwitch {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
         let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let mut owners = Vec::<Pubkey>::new();
         let mut array = Vec::<Box<dyn Node>>::new();
-        let contains = &in_yaml[SAD_YAML_CONTAINS];
-        match contains {
+        match &in_yaml[SAD_YAML_OWNERS] {
             Yaml::Array(lst) => {
                 for hl in lst {
-                    array.push(parse(hl).unwrap())
+                    let owner = hl[SAD_YAML_OWNER]
+                        .as_str()
+                        .and_then(|o| Pubkey::from_str(o).ok())
+                        .ok_or(SadTreeError::ExpectedOwnerSwitchOwners)?;
+                    let name = owner.to_string();
+                    array.push(SadVariant::with_name(
+                        hl[SAD_YAML_NAME].as_str().unwrap_or(&name),
+                        hl,
+                    )?);
+                    owners.push(owner);
                 }
-                Ok(Box::new(SadVector {
+                Ok(Box::new(SadOwnerSwitch {
                     sad_value_type: String::from(in_str),
+                    owners,
                     children: array,
                 }))
             }
-            _ => Err(SadTreeError::ExpectedVecContains),
+            _ => Err(SadTreeError::ExpectedOwnerSwitchOwners),
         }
     }
 }
-impl Node for SadVector {
+
+impl Node for SadOwnerSwitch {
     fn decl_type(&self) -> &String {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        // let mut coll = Vec::<Vec<SadValue>>::new();
-        let count = u32::try_from_slice(&data[0..4]).unwrap();
-        *data = &data[4..];
-        let mut spare = Vec::<SadValue>::new();
-        for _ in 0..count {
-            for c in &self.children {
-                c.deser(data, &mut spare);
-            }
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let owner = match cursor.owner() {
+            Some(owner) => owner,
+            None => return Err(SadTreeError::OwnerRequired),
+        };
+        match self.owners.iter().position(|o| o == owner) {
+            Some(index) => self.children[index].deser(cursor, collection),
+            None => Err(SadTreeError::UnknownOwner(owner.to_string())),
         }
-        collection.push(SadValue::Vec(spare));
     }
 }
 
-impl NodeWithChildren for SadVector {
+impl NodeWithChildren for SadOwnerSwitch {
     fn children(&self) -> &Vec<Box<dyn Node>> {
         &self.children
     }
 }
 
-/// Implements NodeWithChildren for Tuple (i.e. Rust tuple)
+/// Implements NodeWithChildren for selecting a layout by a version number
+/// of 'version_type', read in place or, when 'version_offset' is given,
+/// at that absolute offset without consuming it. Each of 'versions' is
+/// a variant with a 'version', an optional 'name' (defaulting to v<version>)
+/// and 'fields'. Output is as for Enum
 #[derive(Debug)]
-pub struct SadTuple {
+pub struct SadVersioned {
     sad_value_type: String,
+    version_type: String,
+    version_offset: Option<usize>,
+    versions: Vec<u64>,
     children: Vec<Box<dyn Node>>,
 }
 
-impl SadTuple {
+impl SadVersioned {
     fn from_yaml(in_yaml: &Yaml) -> SadTreeResult<Box<dyn Node>> {
         let in_str = in_yaml[SAD_YAML_TYPE].as_str().unwrap();
+        let version_offset = match &in_yaml[SAD_YAML_VERSION_OFFSET] {
+            Yaml::BadValue => None,
+            Yaml::Integer(offset) if *offset >= 0 => Some(*offset as usize),
+            _ => return Err(SadTreeError::ExpectedVersions),
+        };
+        let mut versions = Vec::<u64>::new();
         let mut array = Vec::<Box<dyn Node>>::new();
-        let fields = &in_yaml[SAD_YAML_FIELDS];
-        match fields {
+        match &in_yaml[SAD_YAML_VERSIONS] {
             Yaml::Array(lst) => {
                 for hl in lst {
-                    array.push(parse(hl).unwrap())
+                    let version = match hl[SAD_YAML_VERSION] {
+                        Yaml::Integer(v) if v >= 0 => v as u64,
+                        _ => return Err(SadTreeError::ExpectedVersions),
+                    };
+                    let name = format!("v{}", version);
+                    array.push(SadVariant::with_name(
+                        hl[SAD_YAML_NAME].as_str().unwrap_or(&name),
+                        hl,
+                    )?);
+                    versions.push(version);
                 }
-                Ok(Box::new(SadTuple {
+                Ok(Box::new(SadVersioned {
                     sad_value_type: String::from(in_str),
+                    version_type: unsigned_type_from_yaml(in_yaml, SAD_YAML_VERSION_TYPE, "U8")?,
+                    version_offset,
+                    versions,
                     children: array,
                 }))
             }
-            _ => Err(SadTreeError::ExpectedTupleFields),
+            _ => Err(SadTreeError::ExpectedVersions),
         }
     }
 }
-impl Node for SadTuple {
+
+impl Node for SadVersioned {
     fn decl_type(&self) -> &String {
         &self.sad_value_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        let mut spare = Vec::<SadValue>::new();
-        for c in &self.children {
-            c.deser(data, &mut spare)
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let version = match self.version_offset {
+            Some(offset) => {
+                let position = cursor.position();
+                cursor.seek(offset)?;
+                let version = read_size(&self.version_type, cursor)?;
+                cursor.seek(position)?;
+                version
+            }
+            None => read_size(&self.version_type, cursor)?,
+        } as u64;
+        match self.versions.iter().position(|v| *v == version) {
+            Some(index) => self.children[index].deser(cursor, collection),
+            None => Err(SadTreeError::UnknownVersion(version)),
         }
-        collection.push(SadValue::Tuple(spare));
     }
 }
 
-impl NodeWithChildren for SadTuple {
+impl NodeWithChildren for SadVersioned {
     fn children(&self) -> &Vec<Box<dyn Node>> {
         &self.children
     }
@@ -367,6 +2244,8 @@ pub struct SadTree {
     yaml_decl_type: String,
     name: String,
     varnames: Vec<String>,
+    start_offset: usize,
+    discriminator: Option<[u8; SAD_ANCHOR_DISCRIMINATOR_SIZE]>,
     children: Vec<Box<dyn Node>>,
 }
 
@@ -376,19 +2255,42 @@ impl SadTree {
         let mut vars = Vec::<String>::new();
         match &*in_yaml {
             Yaml::Hash(ref hmap) => {
-                let (key, value) = hmap.front().unwrap();
+                let start_offset = match &in_yaml[SAD_YAML_START_OFFSET] {
+                    Yaml::BadValue => 0,
+                    Yaml::Integer(offset) if *offset >= 0 => *offset as usize,
+                    _ => return Err(SadTreeError::ExpectedStartOffset),
+                };
+                let discriminator = match &in_yaml[SAD_YAML_ANCHOR_ACCOUNT] {
+                    Yaml::BadValue => None,
+                    Yaml::String(account) => Some(anchor_discriminator(account)),
+                    _ => return Err(SadTreeError::ExpectedAnchorAccount),
+                };
+                match &in_yaml[SAD_YAML_ENCODING] {
+                    Yaml::BadValue => {}
+                    Yaml::String(encoding) if encoding == SAD_DEFAULT_ENCODING => {}
+                    Yaml::String(encoding) => {
+                        return Err(SadTreeError::UnknownEncoding(encoding.clone()))
+                    }
+                    other => return Err(SadTreeError::UnknownEncoding(format!("{:?}", other))),
+                }
+                let (key, value) = hmap
+                    .iter()
+                    .find(|(k, _)| !SAD_TREE_ATTRIBUTES.contains(&k.as_str().unwrap_or_default()))
+                    .ok_or(SadTreeError::ExpectedArray)?;
                 match value {
                     Yaml::Array(hlobjects) => {
                         for hl in hlobjects {
                             let (varname, h1_value) = hl.as_hash().unwrap().front().unwrap();
 
                             vars.push(varname.as_str().unwrap().to_string());
-                            array.push(parse(h1_value).unwrap());
+                            array.push(parse(h1_value)?);
                         }
                         Ok(Self {
                             yaml_decl_type: String::from("tree"),
                             name: key.as_str().unwrap().to_string(),
                             varnames: vars,
+                            start_offset,
+                            discriminator,
                             children: array,
                         })
                     }
@@ -402,6 +2304,20 @@ impl SadTree {
     pub fn get_name(&self) -> &String {
         &self.name
     }
+
+    pub fn discriminator(&self) -> Option<&[u8; SAD_ANCHOR_DISCRIMINATOR_SIZE]> {
+        self.discriminator.as_ref()
+    }
+}
+
+/// The Anchor account discriminator for an account type name
+fn anchor_discriminator(account: &str) -> [u8; SAD_ANCHOR_DISCRIMINATOR_SIZE] {
+    let mut discriminator = [0u8; SAD_ANCHOR_DISCRIMINATOR_SIZE];
+    discriminator.copy_from_slice(
+        &hashv(&[format!("account:{}", account).as_bytes()]).to_bytes()
+            [..SAD_ANCHOR_DISCRIMINATOR_SIZE],
+    );
+    discriminator
 }
 
 impl NodeWithChildren for SadTree {
@@ -415,10 +2331,45 @@ impl Node for SadTree {
         &self.yaml_decl_type
     }
 
-    fn deser(&self, data: &mut &[u8], collection: &mut Vec<SadValue>) {
-        for c in &self.children {
-            c.deser(data, collection)
+    fn deser(&self, cursor: &mut SadCursor, collection: &mut Vec<SadValue>) -> SadTreeResult<()> {
+        let data = &mut cursor.data;
+        if self.start_offset > data.len() {
+            return Err(SadTreeError::StartOffsetOutOfRange(
+                self.start_offset,
+                data.len(),
+            ));
+        }
+        *data = &data[self.start_offset..];
+        if let Some(expected) = &self.discriminator {
+            match data.get(..SAD_ANCHOR_DISCRIMINATOR_SIZE) {
+                Some(found) if found == expected => *data = &data[SAD_ANCHOR_DISCRIMINATOR_SIZE..],
+                found => {
+                    return Err(SadTreeError::DiscriminatorMismatch(
+                        expected.to_vec(),
+                        found.unwrap_or(data).to_vec(),
+                    ))
+                }
+            }
+        }
+        for (c, name) in self.children.iter().zip(&self.varnames) {
+            // Data Sections past the end of truncated data are left unavailable
+            if cursor.truncated {
+                let decoded = collection.len();
+                match c.deser(cursor, collection) {
+                    Err(SadTreeError::ReadPastEnd(_, _)) => {
+                        collection.truncate(decoded);
+                        break;
+                    }
+                    result => result?,
+                }
+            } else {
+                c.deser(cursor, collection)?;
+            }
+            if let Some(value) = collection.last() {
+                cursor.fields.insert(name.clone(), value.clone());
+            }
         }
+        Ok(())
     }
 }
 
@@ -459,7 +2410,7 @@ impl SadSchemaElement {
     pub fn sad_to_json(&self, for_data: &Vec<SadValue>) -> Value {
         if let Some(items) = &self.items {
             match self.schema_type.as_str() {
-                "Vec" => {
+                "Vec" | "TaggedVec" | "Array" | "RunLength" | "FillBetween" | "TrailerRef" => {
                     match &for_data[0] {
                         // Get the inner vector and, using the data size, repeat
                         SadValue::Vec(v) => {
@@ -475,6 +2426,60 @@ impl SadSchemaElement {
                         _ => unreachable!(),
                     }
                 }
+                // Key with whether it verified as the PDA
+                "PdaCheck" => match &for_data[0] {
+                    SadValue::Tuple(v) => match v[1] {
+                        SadValue::Bool(valid) => json!({
+                            "key": from_scalar_value_for(&v[0]),
+                            "pda_valid": valid,
+                        }),
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                // Referenced key with the decoded account, null when not followed
+                "FollowRef" => match &for_data[0] {
+                    SadValue::Tuple(v) => json!({
+                        "key": from_scalar_value_for(&v[0]),
+                        "account": match &v[1] {
+                            SadValue::Option(c) if c.is_empty() => Value::Null,
+                            SadValue::Option(c) => items[0].sad_to_json(&vec![c[0].clone()]),
+                            _ => unreachable!(),
+                        },
+                    }),
+                    _ => unreachable!(),
+                },
+                // Text verified as JSON when decoded
+                "JsonText" => match &for_data[0] {
+                    SadValue::String(text) => serde_json::from_str(text).unwrap(),
+                    _ => unreachable!(),
+                },
+                // Stored checksum with whether it matches the data
+                "Checksum" => match &for_data[0] {
+                    SadValue::Tuple(v) => match (&v[0], &v[1]) {
+                        (SadValue::Vec(bytes), SadValue::Bool(valid)) => json!({
+                            "checksum": bytes
+                                .iter()
+                                .map(|b| items[0].sad_to_json(&vec![b.clone()]))
+                                .collect::<Vec<Value>>(),
+                            "checksum_valid": valid,
+                        }),
+                        (stored, SadValue::Bool(valid)) => json!({
+                            "checksum": items[0].sad_to_json(&vec![stored.clone()]),
+                            "checksum_valid": valid,
+                        }),
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                // Raw value with the scaled rendering
+                "Scaled" => match &for_data[0] {
+                    SadValue::Tuple(v) => json!({
+                        "raw": items[0].sad_to_json(&vec![v[0].clone()]),
+                        "scaled": from_scalar_value_for(&v[1]),
+                    }),
+                    _ => unreachable!(),
+                },
                 "Tuple" => match &for_data[0] {
                     SadValue::Tuple(v) => {
                         let mut json_tuple = json!([]);
@@ -512,7 +2517,8 @@ impl SadSchemaElement {
                     }
                     _ => unreachable!(),
                 },
-                "CStruct" => match &for_data[0] {
+                "CStruct" | "FlagString" => match &for_data[0] {
+                    SadValue::String(raw) => json!(raw),
                     SadValue::CStruct(nfs) => {
                         let mut json_cstruct = json!({});
                         let raw_json = json_cstruct.as_object_mut().unwrap();
@@ -546,6 +2552,32 @@ impl SadSchemaElement {
                     }
                     _ => unreachable!(),
                 },
+                "Option" => match &for_data[0] {
+                    SadValue::Option(v) if v.is_empty() => Value::Null,
+                    SadValue::Option(v) => items[0].sad_to_json(&vec![v[0].clone()]),
+                    _ => unreachable!(),
+                },
+                // Rendered as serde does for externally tagged enums
+                "Enum" | "OwnerSwitch" | "Versioned" => match &for_data[0] {
+                    SadValue::Enum(v) => {
+                        let name = from_scalar_value_for(&v[0]);
+                        let variant = items
+                            .iter()
+                            .find(|i| i.schema_ancillary_type.as_ref() == Some(&name))
+                            .unwrap();
+                        let fields = variant.items.as_ref().unwrap();
+                        let mut json_fields = Vec::<Value>::new();
+                        for i in 1..v.len() {
+                            json_fields.push(fields[i - 1].sad_to_json(&vec![v[i].clone()]))
+                        }
+                        match json_fields.len() {
+                            0 => json!(name),
+                            1 => json!({ name: json_fields.pop().unwrap() }),
+                            _ => json!({ name: json_fields }),
+                        }
+                    }
+                    _ => unreachable!(),
+                },
                 _ => unreachable!(),
             }
         } else {
@@ -632,71 +2664,189 @@ impl SadSchema {
             } else {
                 panic!()
             }
-        }
-        itypes
-    }
-
-    /// Given a header string vector, generate a summary
-    /// with item_type keys and hit count: HashMap<String, u64>
-    pub fn gen_type_summary(&self, inhdr: &Vec<String>) -> HashMap<String, u64> {
-        let mut summary = HashMap::<String, u64>::new();
-        let mut keys = Vec::<&String>::new();
-        for k in self.item_type_prefixes() {
-            summary.insert(k.to_string(), 0u64);
-            keys.push(k);
-        }
-        for t in inhdr {
-            for k in keys.iter() {
-                if t.contains(*k) {
-                    *summary.entry(k.to_string()).or_insert(0) += 1;
-                }
+        }
+        itypes
+    }
+
+    /// Given a header string vector, generate a summary
+    /// with item_type keys and hit count: HashMap<String, u64>
+    pub fn gen_type_summary(&self, inhdr: &Vec<String>) -> HashMap<String, u64> {
+        let mut summary = HashMap::<String, u64>::new();
+        let mut keys = Vec::<&String>::new();
+        for k in self.item_type_prefixes() {
+            summary.insert(k.to_string(), 0u64);
+            keys.push(k);
+        }
+        for t in inhdr {
+            for k in keys.iter() {
+                if t.contains(*k) {
+                    *summary.entry(k.to_string()).or_insert(0) += 1;
+                }
+            }
+        }
+        summary
+    }
+
+    /// Given a result of deserialization, generate a JSON
+    /// representation. Items without data, those past the
+    /// end of truncated data, are null
+    fn sad_to_json(&self, with_data: &Vec<SadValue>) -> Value {
+        let mut json_out = json!({});
+        let mut index = 0usize;
+        for item in self.get_items() {
+            match with_data.get(index) {
+                Some(value) => item.sad_to_json(&mut json_out, &vec![value.clone()]),
+                None => {
+                    json_out
+                        .as_object_mut()
+                        .unwrap()
+                        .insert(item.get_name().clone(), Value::Null);
+                }
+            }
+            index += 1;
+        }
+        json_out
+    }
+
+    fn itemize(children: &Vec<Box<dyn Node>>) -> Vec<SadSchemaElement> {
+        let mut items = Vec::<SadSchemaElement>::new();
+        for c in children {
+            SadSchema::schema_item(c, &mut items)
+        }
+        items
+    }
+
+    fn schema_item(node: &Box<dyn Node>, collect: &mut Vec<SadSchemaElement>) {
+        let schm_element = SadSchemaElement::new(&node.decl_type());
+        match node.decl_type().as_str() {
+            "HashMap" => {
+                let lp = node.downcast_ref::<SadHashMap>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Vec" | "TaggedVec" => {
+                let lp = node.downcast_ref::<SadVector>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Array" => {
+                let lp = node.downcast_ref::<SadArray>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "RunLength" => {
+                let lp = node.downcast_ref::<SadRunLength>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "FillBetween" => {
+                let lp = node.downcast_ref::<SadFillBetween>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "TrailerRef" => {
+                let lp = node.downcast_ref::<SadTrailerRef>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Option" => {
+                let lp = node.downcast_ref::<SadOption>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Enum" => {
+                let lp = node.downcast_ref::<SadEnum>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "OwnerSwitch" => {
+                let lp = node.downcast_ref::<SadOwnerSwitch>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Versioned" => {
+                let lp = node.downcast_ref::<SadVersioned>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "Variant" => {
+                let lp = node.downcast_ref::<SadVariant>().unwrap();
+                collect.push(
+                    schm_element
+                        .ancillary_type(lp.name())
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
+            "PdaCheck" => {
+                let lp = node.downcast_ref::<SadPdaCheck>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
             }
-        }
-        summary
-    }
-
-    /// Given a result of deserialization, generate a JSON
-    /// representation
-
-    fn sad_to_json(&self, with_data: &Vec<SadValue>) -> Value {
-        let mut json_out = json!({});
-        let mut index = 0usize;
-        for item in self.get_items() {
-            let mut d = Vec::<SadValue>::new();
-            d.push(with_data[index].clone());
-            item.sad_to_json(&mut json_out, &d);
-            index += 1;
-        }
-        json_out
-    }
-
-    fn itemize(children: &Vec<Box<dyn Node>>) -> Vec<SadSchemaElement> {
-        let mut items = Vec::<SadSchemaElement>::new();
-        for c in children {
-            SadSchema::schema_item(c, &mut items)
-        }
-        items
-    }
-
-    fn schema_item(node: &Box<dyn Node>, collect: &mut Vec<SadSchemaElement>) {
-        let schm_element = SadSchemaElement::new(&node.decl_type());
-        match node.decl_type().as_str() {
-            "HashMap" => {
-                let lp = node.downcast_ref::<SadHashMap>().unwrap();
+            "JsonText" => collect.push(schm_element.scalar(false).items(Vec::new())),
+            "FollowRef" => {
+                let lp = node.downcast_ref::<SadFollowRef>().unwrap();
                 collect.push(
                     schm_element
                         .scalar(false)
                         .items(SadSchema::itemize(lp.children())),
                 )
             }
-            "Vec" => {
-                let lp = node.downcast_ref::<SadVector>().unwrap();
+            // Renders as the value pointed to
+            "Pointer" => {
+                let lp = node.downcast_ref::<SadPointer>().unwrap();
+                SadSchema::schema_item(&lp.children()[0], collect)
+            }
+            "Checksum" => {
+                let lp = node.downcast_ref::<SadChecksum>().unwrap();
                 collect.push(
                     schm_element
                         .scalar(false)
                         .items(SadSchema::itemize(lp.children())),
                 )
             }
+            "Scaled" => {
+                let lp = node.downcast_ref::<SadScaled>().unwrap();
+                collect.push(
+                    schm_element
+                        .ancillary_type(lp.children()[0].decl_type())
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
             "Tuple" => {
                 let lp = node.downcast_ref::<SadTuple>().unwrap();
                 collect.push(
@@ -705,6 +2855,14 @@ impl SadSchema {
                         .items(SadSchema::itemize(lp.children())),
                 )
             }
+            "FlagString" => {
+                let lp = node.downcast_ref::<SadFlagString>().unwrap();
+                collect.push(
+                    schm_element
+                        .scalar(false)
+                        .items(SadSchema::itemize(lp.children())),
+                )
+            }
             "CStruct" => {
                 let lp = node.downcast_ref::<SadStructure>().unwrap();
 
@@ -761,6 +2919,7 @@ impl SadSchema {
 pub struct Deseriaizer {
     sad_schema: SadSchema,
     sad_tree: SadTree,
+    max_bytes: Option<usize>,
 }
 
 impl Deseriaizer {
@@ -770,9 +2929,17 @@ impl Deseriaizer {
         Self {
             sad_tree: tree,
             sad_schema: scm,
+            max_bytes: None,
         }
     }
 
+    /// Only deserialize the first 'max_bytes' of data, Data Sections
+    /// reading past them are unavailable (null in JSON)
+    pub fn with_max_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
     pub fn schema(&self) -> &SadSchema {
         &self.sad_schema
     }
@@ -782,8 +2949,44 @@ impl Deseriaizer {
     }
 
     pub fn deser(&self, data: &mut &[u8]) -> SadTreeResult<Vec<SadValue>> {
+        let mut cursor = SadCursor::new(data).with_max_bytes(self.max_bytes);
+        let hm = self.deser_cursor(&mut cursor)?;
+        *data = cursor.data;
+        Ok(hm)
+    }
+
+    /// Deserialize data of an account owned by 'owner'
+    pub fn deser_owned(&self, data: &mut &[u8], owner: &Pubkey) -> SadTreeResult<Vec<SadValue>> {
+        let mut cursor = SadCursor::new(data)
+            .with_owner(owner)
+            .with_max_bytes(self.max_bytes);
+        let hm = self.deser_cursor(&mut cursor)?;
+        *data = cursor.data;
+        Ok(hm)
+    }
+
+    /// Deserialize data of an account owned by 'owner', fetching the
+    /// accounts its FollowRef nodes reference with 'resolver'
+    pub fn deser_following(
+        &self,
+        data: &mut &[u8],
+        owner: &Pubkey,
+        resolver: &dyn SadAccountResolver,
+    ) -> SadTreeResult<Vec<SadValue>> {
+        let mut cursor = SadCursor::new(data)
+            .with_owner(owner)
+            .with_max_bytes(self.max_bytes)
+            .with_resolver(resolver);
+        let hm = self.deser_cursor(&mut cursor)?;
+        // The cursor is bound to the resolver's lifetime, advance by its position
+        let position = cursor.position();
+        *data = &data[position..];
+        Ok(hm)
+    }
+
+    fn deser_cursor(&self, cursor: &mut SadCursor) -> SadTreeResult<Vec<SadValue>> {
         let mut hm = Vec::<SadValue>::new();
-        self.tree().deser(data, &mut hm);
+        self.tree().deser(cursor, &mut hm)?;
         Ok(hm)
     }
 
@@ -819,7 +3022,7 @@ mod tests {
     use base64::decode;
     use borsh::BorshSerialize;
     use gadgets_common::load_yaml_file;
-    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::{account::Account, pubkey::Pubkey};
     use strum::VariantNames;
     use yaml_rust::YamlLoader;
 
@@ -911,6 +3114,31 @@ mod tests {
             assert!(result.is_ok());
         }
     }
+
+    #[test]
+    fn test_nested_parse_fail() {
+        // Errors in nested fields surface instead of panicking
+        for (body, expected) in [
+            ("type: CStruct\nfields:\n  - type: Bogus\n", "Bogus"),
+            ("type: Tuple\nfields:\n  - type: Bogus\n", "Bogus"),
+            ("type: Vec\ncontains:\n  - type: Bogus\n", "Bogus"),
+            (
+                "type: HashMap\nfields:\n  - type: String\n  - type: Bogus\n",
+                "Bogus",
+            ),
+        ] {
+            let docs = YamlLoader::load_from_str(body).unwrap();
+            assert!(matches!(
+                parse(&docs[0]),
+                Err(SadTreeError::UnknownType(name)) if name == expected
+            ));
+        }
+        let docs = YamlLoader::load_from_str(
+            "type: CStruct\nfields:\n  - type: Vec\n    max_len: -1\n    contains:\n      - type: U8\n",
+        )
+        .unwrap();
+        assert!(matches!(parse(&docs[0]), Err(SadTreeError::ExpectedMaxLen)));
+    }
     #[test]
     fn test_runner_pass() {
         let result = get_runner_yaml();
@@ -1018,4 +3246,1019 @@ mod tests {
             serde_json::to_string_pretty(&desc.to_json(&deserialize_vector)).unwrap()
         );
     }
+
+    #[test]
+    fn test_start_offset_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Offset:\n  - name:\n      type: String\n  - age:\n      type: U32\nstart_offset: 8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = vec![0xffu8; 8];
+        data.extend(
+            OfStruct {
+                name: "Frank".to_string(),
+                age: 64,
+            }
+            .try_to_vec()
+            .unwrap(),
+        );
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({"name": "Frank", "age": 64})
+        );
+        assert!(matches!(
+            desc.deser(&mut &data[..4]),
+            Err(SadTreeError::StartOffsetOutOfRange(8, 4))
+        ));
+    }
+
+    #[test]
+    fn test_run_length_pass() {
+        let docs = YamlLoader::load_from_str(
+            "RunLength:\n  - pixels:\n      type: RunLength\n      count_type: U8\n      contains:\n        - type: U16\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = 2u32.try_to_vec().unwrap();
+        data.extend([3u8, 7, 0, 2u8, 9, 0]);
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({"pixels": [7, 7, 7, 9, 9]})
+        );
+        let mut absurd = 1u32.try_to_vec().unwrap();
+        absurd.extend(u32::MAX.try_to_vec().unwrap());
+        absurd.extend([1u8, 0]);
+        let docs = YamlLoader::load_from_str(
+            "RunLength:\n  - pixels:\n      type: RunLength\n      contains:\n        - type: U16\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            Deseriaizer::new(&docs[0]).deser(&mut absurd.as_slice()),
+            Err(SadTreeError::RunLengthTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_scaled_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Scaled:\n  - price:\n      type: U32\n      scale_numerator: 3\n      scale_denominator: 2\n  - whole:\n      type: I16\n      scale_numerator: 3\n      scale_denominator: 2\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = 5u32.try_to_vec().unwrap();
+        data.extend((-4i16).try_to_vec().unwrap());
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({
+                "price": {"raw": 5, "scaled": "7.5"},
+                "whole": {"raw": -4, "scaled": "-6"}
+            })
+        );
+        let docs = YamlLoader::load_from_str(
+            "Scaled:\n  - price:\n      type: U32\n      scale_denominator: 0\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            SadTree::new(&docs[0]),
+            Err(SadTreeError::ExpectedScaleFactor)
+        ));
+    }
+
+    #[test]
+    fn test_fill_between_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Trailer:\n  - version:\n      type: U16\n  - body:\n      type: FillBetween\n      trailer_size: 8\n  - footer:\n      type: U64\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = 2u16.try_to_vec().unwrap();
+        data.extend([1u8, 2, 3]);
+        data.extend(77u64.try_to_vec().unwrap());
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({"version": 2, "body": [1, 2, 3], "footer": 77})
+        );
+        assert!(matches!(
+            desc.deser(&mut &data[..8]),
+            Err(SadTreeError::TrailerExceedsData(8, 6))
+        ));
+    }
+
+    #[test]
+    fn test_anchor_discriminator_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Anchor:\n  - name:\n      type: String\n  - age:\n      type: U32\nanchor_account: Person\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let discriminator = hashv(&[b"account:Person"]).to_bytes()[..8].to_vec();
+        assert_eq!(desc.tree().discriminator().unwrap().to_vec(), discriminator);
+        let person = OfStruct {
+            name: "Frank".to_string(),
+            age: 64,
+        }
+        .try_to_vec()
+        .unwrap();
+        let mut data = discriminator.clone();
+        data.extend(&person);
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({"name": "Frank", "age": 64})
+        );
+        let mut wrong = vec![0u8; 8];
+        wrong.extend(&person);
+        assert!(matches!(
+            desc.deser(&mut wrong.as_slice()),
+            Err(SadTreeError::DiscriminatorMismatch(_, _))
+        ));
+    }
+
+    #[derive(BorshSerialize)]
+    struct OfBorsh {
+        flag: bool,
+        delta: i16,
+        lamports: u64,
+        label: String,
+        weights: Vec<u32>,
+        limit: Option<u16>,
+        absent: Option<u16>,
+        owner: Pubkey,
+    }
+
+    #[test]
+    fn test_borsh_encoding_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Borsh:
+  - flag:
+      type: Bool
+  - delta:
+      type: I16
+  - lamports:
+      type: U64
+  - label:
+      type: String
+  - weights:
+      type: Vec
+      contains:
+        - type: U32
+  - limit:
+      type: Option
+      contains:
+        - type: U16
+  - absent:
+      type: Option
+      contains:
+        - type: U16
+  - owner:
+      type: PublicKey
+encoding: borsh
+",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let owner = Pubkey::new_unique();
+        let data = OfBorsh {
+            flag: true,
+            delta: -300,
+            lamports: 5_000_000_000,
+            label: "borsh".to_string(),
+            weights: vec![1, 65_536],
+            limit: Some(512),
+            absent: None,
+            owner,
+        }
+        .try_to_vec()
+        .unwrap();
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({
+                "flag": true,
+                "delta": -300,
+                "lamports": 5_000_000_000u64,
+                "label": "borsh",
+                "weights": [1, 65_536],
+                "limit": 512,
+                "absent": null,
+                "owner": owner.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_borsh_encoding_fail() {
+        let docs = YamlLoader::load_from_str(
+            "Bincode:
+  - age:
+      type: U32
+encoding: bincode
+",
+        )
+        .unwrap();
+        assert!(matches!(
+            SadTree::new(&docs[0]),
+            Err(SadTreeError::UnknownEncoding(encoding)) if encoding == "bincode"
+        ));
+    }
+
+    #[test]
+    fn test_leb128_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Leb:\n  - small:\n      type: Leb128U\n  - large:\n      type: Leb128U\n  - max:\n      type: Leb128U\n  - negative:\n      type: Leb128I\n  - positive:\n      type: Leb128I\n  - min:\n      type: Leb128I\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = vec![0x02u8, 0xe5, 0x8e, 0x26];
+        data.extend([0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+        data.extend([0xc0, 0xbb, 0x78, 0x80, 0x01]);
+        data.extend([0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]);
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({
+                "small": 2,
+                "large": 624485,
+                "max": u64::MAX,
+                "negative": -123456,
+                "positive": 128,
+                "min": i64::MIN,
+            })
+        );
+    }
+
+    #[test]
+    fn test_leb128_fail() {
+        let docs = YamlLoader::load_from_str("Leb:\n  - value:\n      type: Leb128U\n").unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        assert!(matches!(
+            desc.deser(&mut [0x80u8, 0x80].as_slice()),
+            Err(SadTreeError::Leb128Truncated)
+        ));
+        assert!(matches!(
+            desc.deser(&mut [0xffu8; 11].as_slice()),
+            Err(SadTreeError::Leb128Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_trailer_ref_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Trailer:\n  - version:\n      type: U16\n  - name:\n      type: TrailerRef\n      trailer_offset: 8\n  - rest:\n      type: FillBetween\n      trailer_size: 8\n  - name_offset:\n      type: U32\n  - name_length:\n      type: U32\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = 1u16.try_to_vec().unwrap();
+        data.extend(b"hello");
+        data.push(0xff);
+        data.extend(2u32.try_to_vec().unwrap());
+        data.extend(5u32.try_to_vec().unwrap());
+        let deserialize_vector = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&deserialize_vector),
+            serde_json::json!({
+                "version": 1,
+                "name": b"hello",
+                "rest": [b'h', b'e', b'l', b'l', b'o', 0xff],
+                "name_offset": 2,
+                "name_length": 5,
+            })
+        );
+        let mut bad = data.clone();
+        let len = bad.len();
+        bad[len - 4..].copy_from_slice(&50u32.to_le_bytes());
+        assert!(matches!(
+            desc.deser(&mut bad.as_slice()),
+            Err(SadTreeError::SeekOutOfRange(52, 16))
+        ));
+    }
+
+    #[test]
+    fn test_owner_switch_pass() {
+        let token = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let docs = YamlLoader::load_from_str(&format!(
+            "Owned:\n  - state:\n      type: OwnerSwitch\n      owners:\n        - owner: {}\n          name: Token\n          fields:\n            - type: U64\n        - owner: {}\n          fields:\n            - type: U8\n            - type: Bool\n",
+            token, other
+        ))
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let amount = 42u64.try_to_vec().unwrap();
+        let result = desc.deser_owned(&mut amount.as_slice(), &token).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"state": {"Token": 42}})
+        );
+        let flags = vec![7u8, 1];
+        let result = desc.deser_owned(&mut flags.as_slice(), &other).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"state": {other.to_string(): [7, true]}})
+        );
+        assert!(matches!(
+            desc.deser_owned(&mut flags.as_slice(), &Pubkey::new_unique()),
+            Err(SadTreeError::UnknownOwner(_))
+        ));
+        assert!(matches!(
+            desc.deser(&mut flags.as_slice()),
+            Err(SadTreeError::OwnerRequired)
+        ));
+    }
+
+    #[test]
+    fn test_versioned_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Account:\n  - account:\n      type: Versioned\n      versions:\n        - version: 1\n          fields:\n            - type: U32\n        - version: 2\n          name: Current\n          fields:\n            - type: U64\n            - type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut v1 = vec![1u8];
+        v1.extend(7u32.try_to_vec().unwrap());
+        let result = desc.deser(&mut v1.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"account": {"v1": 7}})
+        );
+        let mut v2 = vec![2u8];
+        v2.extend(9u64.try_to_vec().unwrap());
+        v2.push(1);
+        let result = desc.deser(&mut v2.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"account": {"Current": [9, true]}})
+        );
+        v2[0] = 3;
+        assert!(matches!(
+            desc.deser(&mut v2.as_slice()),
+            Err(SadTreeError::UnknownVersion(3))
+        ));
+    }
+
+    #[test]
+    fn test_versioned_offset_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Account:\n  - account:\n      type: Versioned\n      version_type: U16\n      version_offset: 4\n      versions:\n        - version: 1\n          fields:\n            - type: U32\n            - type: U16\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = 5u32.try_to_vec().unwrap();
+        data.extend(1u16.try_to_vec().unwrap());
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"account": {"v1": [5, 1]}})
+        );
+    }
+
+    #[test]
+    fn test_string_tag_enum_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Shapes:\n  - first:\n      type: Enum\n      discriminant: String\n      variants:\n        - name: Circle\n          fields:\n            - type: U16\n        - name: Rect\n          fields:\n            - type: U8\n            - type: U8\n  - second:\n      type: Enum\n      discriminant: CString\n      size: 8\n      variants:\n        - name: Circle\n          fields:\n            - type: U16\n        - name: Rect\n          fields:\n            - type: U8\n            - type: U8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = vec![4u8, 0, 0, 0];
+        data.extend(b"Rect");
+        data.extend([2, 3]);
+        data.extend(b"Circle\0\0");
+        data.extend(9u16.to_le_bytes());
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"first": {"Rect": [2, 3]}, "second": {"Circle": 9}})
+        );
+        data[4..8].copy_from_slice(b"Oval");
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::UnknownEnumName(name)) if name == "Oval"
+        ));
+        let docs = YamlLoader::load_from_str(
+            "Shapes:\n  - first:\n      type: Enum\n      discriminant: CString\n      variants:\n        - name: Circle\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            SadTree::new(&docs[0]),
+            Err(SadTreeError::ExpectedEnumDiscriminant)
+        ));
+    }
+
+    #[test]
+    fn test_tagged_vec_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Multisig:\n  - instructions:\n      type: TaggedVec\n      variants:\n        - name: AddMember\n          fields:\n            - type: PublicKey\n        - name: ChangeThreshold\n          fields:\n            - type: U16\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let member = Pubkey::new_unique();
+        let mut data = vec![2u8, 1];
+        data.extend(3u16.try_to_vec().unwrap());
+        data.push(0);
+        data.extend(member.to_bytes());
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"instructions": [
+                {"ChangeThreshold": 3},
+                {"AddMember": member.to_string()}
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_bincode_option_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Bincode:\n  - authority:\n      type: Option\n      mode: bincode\n      contains:\n        - type: U32\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut some = vec![1u8];
+        some.extend(9u32.try_to_vec().unwrap());
+        some.push(1);
+        let result = desc.deser(&mut some.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"authority": 9, "flag": true})
+        );
+        let none = vec![0u8, 0];
+        let result = desc.deser(&mut none.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"authority": null, "flag": false})
+        );
+        let invalid = vec![2u8, 0, 0, 0, 0, 0];
+        assert!(matches!(
+            desc.deser(&mut invalid.as_slice()),
+            Err(SadTreeError::InvalidOptionTag(2))
+        ));
+    }
+
+    #[test]
+    fn test_auto_option_width_fail() {
+        let docs = YamlLoader::load_from_str(
+            "COption:\n  - delegate:\n      type: Option\n      mode: auto\n      size_type: U32\n      contains:\n        - type: U32\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut spl = 1u32.try_to_vec().unwrap();
+        spl.extend(7u32.try_to_vec().unwrap());
+        let result = desc.deser(&mut spl.as_slice()).unwrap();
+        assert_eq!(desc.to_json(&result), serde_json::json!({"delegate": 7}));
+        let mut legacy = vec![1u8];
+        legacy.extend(7u32.try_to_vec().unwrap());
+        let err = desc.deser(&mut legacy.as_slice()).unwrap_err();
+        assert!(matches!(
+            &err,
+            SadTreeError::OptionTagWidthMismatch(_, declared, other) if declared == "U32" && other == "U8"
+        ));
+        assert!(err.to_string().contains("try size_type: U8"));
+    }
+
+    #[test]
+    fn test_auto_option_u8_width_fail() {
+        let docs = YamlLoader::load_from_str(
+            "Borsh:\n  - delegate:\n      type: Option\n      mode: auto\n      size_type: U8\n      contains:\n        - type: U32\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let borsh = Some(7u32).try_to_vec().unwrap();
+        let result = desc.deser(&mut borsh.as_slice()).unwrap();
+        assert_eq!(desc.to_json(&result), serde_json::json!({"delegate": 7}));
+        let mut spl = 1u32.try_to_vec().unwrap();
+        spl.extend(7u32.try_to_vec().unwrap());
+        let err = desc.deser(&mut spl.as_slice()).unwrap_err();
+        assert!(matches!(
+            &err,
+            SadTreeError::OptionTagWidthMismatch(1, declared, other) if declared == "U8" && other == "U32"
+        ));
+        assert!(err.to_string().contains("try size_type: U32"));
+    }
+
+    #[test]
+    fn test_checksum_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Checked:\n  - body:\n      type: U64\n  - crc:\n      type: Checksum\n      algorithm: CRC32\n  - digest:\n      type: Checksum\n      algorithm: Sha256\n      size: 4\n      end: 8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let body = 42u64.try_to_vec().unwrap();
+        let crc = crc32fast::hash(&body);
+        let digest = hashv(&[&body]).to_bytes();
+        let mut data = body.clone();
+        data.extend(crc.try_to_vec().unwrap());
+        data.extend(&digest[..4]);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "body": 42,
+                "crc": {"checksum": crc, "checksum_valid": true},
+                "digest": {"checksum": digest[..4], "checksum_valid": true},
+            })
+        );
+        data[0] = 43;
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "body": 43,
+                "crc": {"checksum": crc, "checksum_valid": false},
+                "digest": {"checksum": digest[..4], "checksum_valid": false},
+            })
+        );
+        let docs = YamlLoader::load_from_str(
+            "Checked:\n  - crc:\n      type: Checksum\n      algorithm: CRC32\n      end: 64\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            Deseriaizer::new(&docs[0]).deser(&mut data.as_slice()),
+            Err(SadTreeError::ChecksumRangeOutOfBounds(0, 64, 16))
+        ));
+    }
+
+    #[test]
+    fn test_seek_restore_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Header:\n  - name_offset:\n      type: U8\n  - to_name:\n      type: Seek\n      field: name_offset\n      offset: 2\n  - name:\n      type: U16\n  - back:\n      type: Restore\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let data = vec![3u8, 1, 0, 0, 0, 7, 0];
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"name_offset": 3, "to_name": 5, "name": 7, "back": 1, "flag": true})
+        );
+        let beyond = vec![9u8, 1, 0];
+        assert!(matches!(
+            desc.deser(&mut beyond.as_slice()),
+            Err(SadTreeError::SeekOutOfRange(11, 3))
+        ));
+        let docs = YamlLoader::load_from_str("Header:\n  - back:\n      type: Restore\n").unwrap();
+        assert!(matches!(
+            Deseriaizer::new(&docs[0]).deser(&mut data.as_slice()),
+            Err(SadTreeError::RestoreWithoutSeek)
+        ));
+    }
+
+    #[test]
+    fn test_pointer_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Header:\n  - name:\n      type: String\n  - again:\n      type: Pointer\n      size_type: U8\n      contains:\n        - type: String\n  - back:\n      type: Pointer\n      size_type: U16\n      relative: true\n      contains:\n        - type: String\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        // Both pointers resolve to the earlier decoded name, the relative
+        // one by -8 from its position
+        let mut data = vec![3u8, 0, 0, 0, b'a', b'b', b'c', 0];
+        data.extend((-8i16).to_le_bytes());
+        data.push(1);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"name": "abc", "again": "abc", "back": "abc", "flag": true})
+        );
+        data[7] = 40;
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::PointerOutOfRange(40, 11))
+        ));
+        data[7] = 0;
+        data[8] = 0xf0;
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::PointerOutOfRange(-8, 11))
+        ));
+        let docs = YamlLoader::load_from_str(
+            "Looped:\n  - head:\n      type: Pointer\n      size_type: U8\n      contains:\n        - type: Pointer\n          size_type: U8\n          contains:\n            - type: U8\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            Deseriaizer::new(&docs[0]).deser(&mut vec![0u8, 1].as_slice()),
+            Err(SadTreeError::PointerCycle(0))
+        ));
+    }
+
+    #[test]
+    fn test_header_trailing_vec_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Registry:\n  - version:\n      type: U8\n  - authority:\n      type: PublicKey\n  - created_at:\n      type: I64\n  - entries:\n      type: Vec\n      contains:\n        - type: U64\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let authority = Pubkey::new_unique();
+        let mut data = vec![2u8];
+        data.extend(authority.to_bytes());
+        data.extend((-5i64).to_le_bytes());
+        // The count follows the 41 byte header, elements from 45
+        data.extend(4u32.to_le_bytes());
+        for entry in [10u64, 20, 30, u64::MAX] {
+            data.extend(entry.to_le_bytes());
+        }
+        assert_eq!(data.len(), 45 + 4 * 8);
+        let mut remaining = data.as_slice();
+        let result = desc.deser(&mut remaining).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "version": 2,
+                "authority": authority.to_string(),
+                "created_at": -5,
+                "entries": [10, 20, 30, u64::MAX],
+            })
+        );
+        // The last element runs past the end of the data at its offset
+        data.pop();
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::ReadPastEnd(69, 8))
+        ));
+    }
+
+    #[test]
+    fn test_follow_ref_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Holder:\n  - mint:\n      type: FollowRef\n      contains:\n        - type: CStruct\n          fields:\n            - type: NamedField\n              descriptor:\n                name: supply\n                contains:\n                  type: U64\n            - type: NamedField\n              descriptor:\n                name: authority\n                contains:\n                  type: FollowRef\n                  contains:\n                    - type: U8\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut mint_data = 1000u64.to_le_bytes().to_vec();
+        mint_data.extend(authority.to_bytes());
+        let mint_account = Account {
+            data: mint_data,
+            ..Account::default()
+        };
+        let mut data = mint.to_bytes().to_vec();
+        data.push(1);
+        // Only the mint resolves, the authority inside it is not followed
+        let resolver = |key: &Pubkey| (*key == mint).then(|| mint_account.clone());
+        let result = desc
+            .deser_following(&mut data.as_slice(), &Pubkey::new_unique(), &resolver)
+            .unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "mint": {
+                    "key": mint.to_string(),
+                    "account": {
+                        "supply": 1000,
+                        "authority": {"key": authority.to_string(), "account": null},
+                    },
+                },
+                "flag": true,
+            })
+        );
+        // Without a resolver the reference is output unfollowed
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"mint": {"key": mint.to_string(), "account": null}, "flag": true})
+        );
+        let short = |_: &Pubkey| {
+            Some(Account {
+                data: vec![1, 2],
+                ..Account::default()
+            })
+        };
+        assert!(matches!(
+            desc.deser_following(&mut data.as_slice(), &Pubkey::new_unique(), &short),
+            Err(SadTreeError::FollowRefFailed(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_max_bytes_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Header:\n  - version:\n      type: U8\n  - owner:\n      type: U32\n  - name:\n      type: String\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let mut data = vec![1u8, 7, 0, 0, 0, 3, 0, 0, 0];
+        data.extend(b"abc");
+        data.push(1);
+        let desc = Deseriaizer::new(&docs[0]);
+        let full = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&full),
+            serde_json::json!({"version": 1, "owner": 7, "name": "abc", "flag": true})
+        );
+        // The boundary falls inside name so it and flag are unavailable
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(7));
+        let header = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(header.len(), 2);
+        assert_eq!(
+            desc.to_json(&header),
+            serde_json::json!({"version": 1, "owner": 7, "name": null, "flag": null})
+        );
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(64));
+        let unlimited = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(desc.to_json(&unlimited), desc.to_json(&full));
+        // Without truncation a read past the end fails
+        assert!(matches!(
+            Deseriaizer::new(&docs[0]).deser(&mut data[..7].as_ref()),
+            Err(SadTreeError::ReadPastEnd(5, 4))
+        ));
+        // Boundaries inside a PublicKey and inside a Leb128U
+        let docs = YamlLoader::load_from_str(
+            "Keyed:\n  - version:\n      type: U8\n  - key:\n      type: PublicKey\n  - amount:\n      type: Leb128U\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let key = Pubkey::new_unique();
+        let mut data = vec![1u8];
+        data.extend(key.to_bytes());
+        data.extend([0xac, 0x02, 1]);
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(10));
+        let header = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&header),
+            serde_json::json!({"version": 1, "key": null, "amount": null, "flag": null})
+        );
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(34));
+        let header = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&header),
+            serde_json::json!({"version": 1, "key": key.to_string(), "amount": null, "flag": null})
+        );
+        let desc = Deseriaizer::new(&docs[0]);
+        let full = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&full),
+            serde_json::json!({"version": 1, "key": key.to_string(), "amount": 300, "flag": true})
+        );
+    }
+
+    #[test]
+    fn test_max_bytes_prefix_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Header:\n  - version:\n      type: U8\n  - votes:\n      type: Vec\n      contains:\n        - type: U8\n  - label:\n      type: CString\n      size: 8\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let mut data = vec![1u8, 2, 0, 0, 0, 9, 9];
+        data.extend(b"abc\0\0\0\0\0");
+        data.push(1);
+        // The boundary falls inside the length prefix of votes
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(3));
+        let header = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&header),
+            serde_json::json!({"version": 1, "votes": null, "label": null, "flag": null})
+        );
+        // The boundary falls inside label
+        let desc = Deseriaizer::new(&docs[0]).with_max_bytes(Some(10));
+        let header = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&header),
+            serde_json::json!({"version": 1, "votes": [9, 9], "label": null, "flag": null})
+        );
+    }
+
+    #[test]
+    fn test_cstring_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Named:\n  - name:\n      type: CString\n      size: 32\n  - tag:\n      type: CString\n      size: 4\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = b"gadget".to_vec();
+        data.resize(32, 0);
+        data.extend(b"FULL");
+        data.push(1);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"name": "gadget", "tag": "FULL", "flag": true})
+        );
+        data[0] = 0xff;
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::InvalidCString(_))
+        ));
+        assert!(matches!(
+            desc.deser(&mut &data[..16]),
+            Err(SadTreeError::ReadPastEnd(0, 32))
+        ));
+    }
+
+    #[test]
+    fn test_json_text_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Config:\n  - version:\n      type: U8\n  - config:\n      type: JsonText\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = vec![2u8];
+        data.extend(br#"{"name": "gadget", "limits": {"max": 5, "tags": ["a", "b"]}}"#);
+        data.extend([0u8; 8]);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "version": 2,
+                "config": {"name": "gadget", "limits": {"max": 5, "tags": ["a", "b"]}}
+            })
+        );
+        let invalid = [2u8, b'{', b'"', b'a'];
+        assert!(matches!(
+            desc.deser(&mut &invalid[..]),
+            Err(SadTreeError::InvalidJsonText(_))
+        ));
+        let not_utf8 = [2u8, b'"', 0xff, b'"'];
+        assert!(matches!(
+            desc.deser(&mut &not_utf8[..]),
+            Err(SadTreeError::InvalidJsonText(_))
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_pass() {
+        assert_eq!(SadTimestamp::utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(SadTimestamp::utc(-1), "1969-12-31T23:59:59Z");
+        assert_eq!(SadTimestamp::utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(SadTimestamp::utc(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn test_pda_check_pass() {
+        let program = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let (vault, _) = Pubkey::find_program_address(&[b"vault", authority.as_ref()], &program);
+        let descriptor = |literal: &str| {
+            format!(
+                "Vault:\n  - authority:\n      type: PublicKey\n  - vault:\n      type: PublicKey\n      pda_check:\n        program: {}\n        seeds:\n          - literal: {}\n          - field: authority\n",
+                program, literal
+            )
+        };
+        let mut data = authority.to_bytes().to_vec();
+        data.extend(vault.to_bytes());
+
+        let docs = YamlLoader::load_from_str(&descriptor("vault")).unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "authority": authority.to_string(),
+                "vault": {"key": vault.to_string(), "pda_valid": true}
+            })
+        );
+
+        let docs = YamlLoader::load_from_str(&descriptor("other")).unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result)["vault"],
+            serde_json::json!({"key": vault.to_string(), "pda_valid": false})
+        );
+    }
+
+    #[test]
+    fn test_byte_length_vec_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Sized:\n  - values:\n      type: Vec\n      byte_length: true\n      size_type: U8\n      contains:\n        - type: U16\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let data = vec![6u8, 1, 0, 2, 0, 3, 0, 1];
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"values": [1, 2, 3], "flag": true})
+        );
+        let uneven = vec![5u8, 1, 0, 2, 0, 3, 0, 1];
+        assert!(matches!(
+            desc.deser(&mut uneven.as_slice()),
+            Err(SadTreeError::ByteLengthMismatch(5, 6))
+        ));
+        let short = vec![9u8, 1, 0];
+        assert!(matches!(
+            desc.deser(&mut short.as_slice()),
+            Err(SadTreeError::ReadPastEnd(1, 9))
+        ));
+    }
+
+    #[test]
+    fn test_byte_length_vec_progress_fail() {
+        let docs = YamlLoader::load_from_str(
+            "Sized:\n  - values:\n      type: Vec\n      byte_length: true\n      size_type: U8\n      contains:\n        - type: U8\n        - type: Seek\n          offset: 1\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let data = vec![2u8, 1, 2];
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::ByteLengthNoProgress(1))
+        ));
+    }
+
+    #[test]
+    fn test_max_len_fail() {
+        let docs = YamlLoader::load_from_str(
+            "Guarded:\n  - name:\n      type: String\n      max_len: 8\n  - votes:\n      type: Vec\n      max_len: 2\n      contains:\n        - type: U8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = String::from("sad").try_to_vec().unwrap();
+        data.extend(vec![1u8, 2].try_to_vec().unwrap());
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"name": "sad", "votes": [1, 2]})
+        );
+
+        let mut data = String::from("sad").try_to_vec().unwrap();
+        data.extend(u32::MAX.to_le_bytes());
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::LengthExceedsMax(len, 2)) if len == u32::MAX as usize
+        ));
+        let data = String::from("too long a name").try_to_vec().unwrap();
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::LengthExceedsMax(15, 8))
+        ));
+        // Prefixes cut short are reported, not read past
+        assert!(matches!(
+            desc.deser(&mut &[3u8, 0][..]),
+            Err(SadTreeError::ReadPastEnd(0, 4))
+        ));
+        let mut data = String::from("sad").try_to_vec().unwrap();
+        data.extend([2u8, 0]);
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::ReadPastEnd(7, 4))
+        ));
+    }
+
+    #[test]
+    fn test_short_u16_string_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Compact:
+  - memo:
+      type: String
+      size_type: ShortU16
+  - done:
+      type: Bool
+",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        // 200 needs two compact-u16 bytes, 0xc8 0x01
+        let memo = "m".repeat(200);
+        let mut data = vec![0xc8u8, 0x01];
+        data.extend(memo.as_bytes());
+        data.push(1);
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"memo": memo, "done": true})
+        );
+
+        let data = vec![0x05u8, b'h', b'e', b'l', b'l', b'o', 0];
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({"memo": "hello", "done": false})
+        );
+        // Longer than needed and beyond 16 bits
+        for data in [vec![0x85u8, 0x00, 0], vec![0xffu8, 0xff, 0x04, 0]] {
+            assert!(matches!(
+                desc.deser(&mut data.as_slice()),
+                Err(SadTreeError::InvalidShortU16)
+            ));
+        }
+        let data = vec![0xc8u8, 0x01, b'm'];
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::ReadPastEnd(2, 200))
+        ));
+    }
+
+    #[test]
+    fn test_flag_string_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Legacy:\n  - mode:\n      type: FlagString\n      size: 3\n      flags: [read, write, execute]\n  - raw:\n      type: FlagString\n      size: 2\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let data = b"RW-ab".to_vec();
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            desc.to_json(&result),
+            serde_json::json!({
+                "mode": {"read": true, "write": true, "execute": false},
+                "raw": "ab"
+            })
+        );
+        let data = b"R\xffX".to_vec();
+        assert!(matches!(
+            desc.deser(&mut data.as_slice()),
+            Err(SadTreeError::InvalidFlagString(_))
+        ));
+    }
 }
@@ -22,14 +22,138 @@ pub enum SadTreeError {
     ExpectedCStructFields,
     #[error("Expected Length Prefix Schema Ancillary Type")]
     ExpectedLengthSchemaType,
+    #[error("Expected Array size and contains")]
+    ExpectedArraySize,
+    #[error("Expected Option contains")]
+    ExpectedOptionContains,
+    #[error("Expected Enum variants")]
+    ExpectedEnumVariants,
+    #[error("Enum tag {0} does not match a declared variant")]
+    UnknownEnumTag(usize),
+    #[error("Expected Enum discriminant to be String or CString with a size")]
+    ExpectedEnumDiscriminant,
+    #[error("Enum discriminant '{0}' does not match a declared variant name")]
+    UnknownEnumName(String),
+    #[error("Expected start_offset to be a non-negative integer")]
+    ExpectedStartOffset,
+    #[error("start_offset {0} exceeds account data length {1}")]
+    StartOffsetOutOfRange(usize, usize),
+    #[error("Expected RunLength contains")]
+    ExpectedRunLengthContains,
+    #[error("RunLength expands beyond {0} elements")]
+    RunLengthTooLarge(usize),
+    #[error("Expected scale_numerator and scale_denominator to be non-zero integers")]
+    ExpectedScaleFactor,
+    #[error("Scaling requires a numeric type, found {0}")]
+    ExpectedScaleNumeric(String),
+    #[error("Expected FillBetween trailer_size")]
+    ExpectedTrailerSize,
+    #[error("Trailer of {0} bytes exceeds the {1} bytes remaining")]
+    TrailerExceedsData(usize, usize),
+    #[error("Expected anchor_account to be an account type name")]
+    ExpectedAnchorAccount,
+    #[error("Expected account discriminator {0:?}, found {1:?}")]
+    DiscriminatorMismatch(Vec<u8>, Vec<u8>),
+    #[error("LEB128 integer ends before its last byte")]
+    Leb128Truncated,
+    #[error("LEB128 integer exceeds 64 bits")]
+    Leb128Overflow,
+    #[error("Compact-u16 length exceeds 16 bits or is not minimally encoded")]
+    InvalidShortU16,
+    #[error("String {0:?} is not valid UTF-8")]
+    InvalidString(String),
+    #[error("Position {0} is beyond the account data length {1}")]
+    SeekOutOfRange(usize, usize),
+    #[error("Expected Seek offset and/or field")]
+    ExpectedSeekOffset,
+    #[error("Seek field {0} is not a previously decoded integer")]
+    InvalidSeekField(String),
+    #[error("Reading {1} bytes at {0} passes the end of the data")]
+    ReadPastEnd(usize, usize),
+    #[error("Restore without a preceding Seek")]
+    RestoreWithoutSeek,
+    #[error("Expected Pointer to contain a single descriptor")]
+    ExpectedPointerContains,
+    #[error("Expected Pointer relative to be true or false")]
+    ExpectedPointerRelative,
+    #[error("Pointer to {0} is outside the data of length {1}")]
+    PointerOutOfRange(i128, usize),
+    #[error("Pointer to {0} is followed again while decoding its own target")]
+    PointerCycle(usize),
+    #[error("Expected FollowRef to contain a single descriptor")]
+    ExpectedFollowRefContains,
+    #[error("Failed decoding account {0} referenced by FollowRef: {1}")]
+    FollowRefFailed(String, String),
+    #[error("Expected TrailerRef trailer_offset")]
+    ExpectedTrailerOffset,
+    #[error("Expected OwnerSwitch owners, each with an owner pubkey")]
+    ExpectedOwnerSwitchOwners,
+    #[error("OwnerSwitch requires the account owner")]
+    OwnerRequired,
+    #[error("Account owner {0} has no OwnerSwitch layout")]
+    UnknownOwner(String),
+    #[error("Expected Versioned versions, each with a version number")]
+    ExpectedVersions,
+    #[error("Descriptor has no layout for version {0}")]
+    UnknownVersion(u64),
+    #[error("Unknown descriptor encoding {0}, expected borsh")]
+    UnknownEncoding(String),
+    #[error("Unknown Option mode {0}, expected borsh or bincode")]
+    UnknownOptionMode(String),
+    #[error("Option tag {0} is neither 0 (None) nor 1 (Some)")]
+    InvalidOptionTag(usize),
+    #[error("Option tag {0} read as {1} does not match the data, which has a valid {2} tag, try size_type: {2}")]
+    OptionTagWidthMismatch(usize, String, String),
+    #[error("Expected pda_check seeds, each a literal, bytes or field")]
+    ExpectedPdaSeeds,
+    #[error("Expected pda_check program to be owner or a pubkey")]
+    ExpectedPdaProgram,
+    #[error("PDA seed field {0} is not a previously decoded scalar")]
+    InvalidSeedField(String),
+    #[error("Expected byte_length to be true or false")]
+    ExpectedByteLength,
+    #[error("Vec elements end at {1} bytes, not at its byte length {0}")]
+    ByteLengthMismatch(usize, usize),
+    #[error("Vec element at {0} consumed no bytes, its byte length is never reached")]
+    ByteLengthNoProgress(usize),
+    #[error("Expected max_len to be a non negative integer")]
+    ExpectedMaxLen,
+    #[error("Length prefix {0} exceeds max_len {1}")]
+    LengthExceedsMax(usize, usize),
+    #[error("Expected JsonText size to be a positive integer")]
+    ExpectedJsonTextSize,
+    #[error("JsonText is not valid UTF-8 JSON: {0}")]
+    InvalidJsonText(String),
+    #[error("Expected CString size to be a positive integer")]
+    ExpectedCStringSize,
+    #[error("CString {0:?} is not valid UTF-8")]
+    InvalidCString(String),
+    #[error("Expected Fragment name")]
+    ExpectedFragmentName,
+    #[error("Unknown Fragment {0}")]
+    UnknownFragment(String),
+    #[error("Expected Checksum algorithm CRC32 or Sha256, a Sha256 size of 1 to 32 and non negative start and end")]
+    ExpectedChecksum,
+    #[error("Checksum range {0}..{1} is outside the {2} bytes of account data")]
+    ChecksumRangeOutOfBounds(usize, usize, usize),
+    #[error("Expected FlagString size, a single ASCII unset character and at most size flags")]
+    ExpectedFlagString,
+    #[error("Invalid ASCII flag string {0:?}")]
+    InvalidFlagString(String),
 }
 
 #[derive(Error, Debug)]
 pub enum SadAccountErrorType {
     #[error("Failed getting Account from cluster")]
     FailedAccountGet,
-    #[error("Failed getting Program Accounts from cluster")]
-    FailedProgramAccountGet,
+    #[error("Failed deserializing account {0}: {1}")]
+    FailedAccountDeserialize(String, String),
+    #[error("Failed creating a pool of {0} threads")]
+    ThreadPoolSetupFail(usize),
+    #[error("Failed getting rent exemption minimum from cluster")]
+    FailedRentGet,
+    #[error("Failed getting Program Accounts from cluster after {0} attempts")]
+    FailedProgramAccountGet(u32),
     #[error("Could not resolve Solana config")]
     ConfigFileError,
     #[error("RcpClient creation failed")]
@@ -44,6 +168,8 @@ pub enum SadAccountErrorType {
 pub enum SadAppErrorType {
     #[error("Row expected {0} elements. found {1}")]
     InconsistentRowLength(usize, usize),
+    #[error("Select path {0} does not resolve")]
+    SelectPathNotFound(String),
 }
 
 pub type SadTreeResult<T> = std::result::Result<T, SadTreeError>;
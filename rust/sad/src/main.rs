@@ -2,11 +2,16 @@
 
 use std::rc::Rc;
 
-use clparse::get_account_and_descriptor;
+use clparse::{
+    get_account_and_descriptor, get_candidate_descriptors, get_diff_targets, get_target_pubkey,
+};
 
 use {
     desertree::Deseriaizer,
-    sadout::{SadJsonOutput, SadOutput, SadSysOutput},
+    gadgets_common::RetryPolicy,
+    sadout::{
+        SadDiffOutput, SadFlatOutput, SadJsonOutput, SadOutput, SadSelectOutput, SadSysOutput,
+    },
     solana_clap_utils::{input_validators::normalize_to_url_if_moniker, keypair::DefaultSigner},
     solana_client::rpc_client::RpcClient,
     solana_remote_wallet::remote_wallet::RemoteWalletManager,
@@ -18,6 +23,7 @@ use {
 mod clparse;
 mod desertree;
 mod errors;
+mod presets;
 mod sadout;
 mod sadtypes;
 mod solq;
@@ -75,6 +81,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     let rpc_client = RpcClient::new(config.json_rpc_url.clone());
 
+    // Try each candidate declaration and report those that decode the account
+    if sub_command == "identify" {
+        let candidates: Vec<(String, Deseriaizer)> = get_candidate_descriptors(matches)
+            .iter()
+            .map(|(name, indecl)| (name.clone(), Deseriaizer::new(&indecl[0])))
+            .collect();
+        let found = solq::identify(&rpc_client, &get_target_pubkey(matches), &candidates)?;
+        match found.first() {
+            Some(best) => println!(
+                "Best match {} with confidence {:.0}% ({} bytes consumed, {} checks passed)",
+                best.name(),
+                best.confidence() * 100.0,
+                best.consumed(),
+                best.checks_passed()
+            ),
+            None => println!("No declaration decoded the account"),
+        }
+        for other in found.iter().skip(1) {
+            println!(
+                "Also decoded by {} with confidence {:.0}% ({} bytes consumed, {} checks passed)",
+                other.name(),
+                other.confidence() * 100.0,
+                other.consumed(),
+                other.checks_passed()
+            );
+        }
+        return Ok(());
+    }
+
+    // Decode both accounts with the descriptor and report the fields that differ
+    if sub_command == "diff" {
+        let (key_a, key_b, indecl) = get_diff_targets(matches);
+        let destree = Deseriaizer::new(&indecl[0]);
+        let (a, b) = solq::deserialize_account_pair(&rpc_client, &key_a, &key_b, &destree)?;
+        SadDiffOutput::new(a, b, destree).write()?;
+        return Ok(());
+    }
+
     // Arguments specific to deserialization
 
     // Setup the account or program public key
@@ -89,23 +133,57 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // });
 
     // Setup the deserialization tree
-    let destree = Deseriaizer::new(&indecl[0]);
+    let destree = Deseriaizer::new(&indecl[0])
+        .with_max_bytes(matches.value_of("max_bytes").map(|n| n.parse().unwrap()));
 
     // Get deserialization results
-    let deserialize_result = match sub_command {
+    let mut deserialize_result = match sub_command {
         "account" => solq::deserialize_account(&rpc_client, &target_pubkey, &destree)?,
-        "program" => solq::deserialize_program_accounts(&rpc_client, &target_pubkey, &destree)?,
+        "program" => {
+            let retry = RetryPolicy {
+                max_attempts: matches.value_of("retries").unwrap().parse().unwrap(),
+                ..RetryPolicy::default()
+            };
+            let order = match matches.value_of("sort").unwrap() {
+                "size" => solq::AccountOrder::DataSize,
+                _ => solq::AccountOrder::Pubkey,
+            };
+            solq::deserialize_program_accounts(
+                &rpc_client,
+                &target_pubkey,
+                &destree,
+                &retry,
+                order,
+                matches.value_of("threads").unwrap().parse().unwrap(),
+            )?
+        }
         _ => unreachable!(),
     };
-    // Check for output or default to pretty print
+    if matches.is_present("rent") {
+        deserialize_result.fetch_rent(&rpc_client)?;
+    }
+    if matches.is_present("ui_amount") {
+        if matches.value_of("preset") != Some("token-account") {
+            eprintln!("error: '--ui-amount' requires '--preset token-account'");
+            exit(1);
+        }
+        deserialize_result.fetch_ui_amounts(&rpc_client);
+    }
+    // Check for a selected value, output or default to pretty print
+    if let Some(path) = matches.value_of("select") {
+        SadSelectOutput::new(deserialize_result, destree, path).write()?;
+        return Ok(());
+    }
     match matches.value_of("output").unwrap() {
         "json" => SadJsonOutput::new(
             deserialize_result,
             destree,
             matches.value_of("filename").unwrap(),
         )
+        .with_numbers_as_strings(matches.is_present("numbers_as_strings"))
         .write()?,
         "stdout" => SadSysOutput::new(deserialize_result, destree).write()?,
+        "flat" => SadFlatOutput::new(deserialize_result, destree).write()?,
         _ => unreachable!(),
     };
     Ok(())
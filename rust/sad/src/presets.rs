@@ -0,0 +1,217 @@
+//! @brief Packaged descriptors for well known Solana accounts
+
+use {
+    lazy_static::*,
+    std::collections::HashMap,
+    yaml_rust::{Yaml, YamlLoader},
+};
+
+/// Preset names available to '--preset'
+pub const SAD_PRESET_NAMES: &[&str] = &["stake", "vote", "metaplex-metadata", "token-account"];
+
+lazy_static! {
+    /// Preset name -> packaged YAML descriptor
+    static ref SAD_PRESETS: HashMap<&'static str, &'static str> = {
+        let mut presets = HashMap::<&str, &str>::new();
+        presets.insert("stake", include_str!("../presets/stake.yml"));
+        presets.insert("vote", include_str!("../presets/vote.yml"));
+        presets.insert(
+            "metaplex-metadata",
+            include_str!("../presets/metaplex-metadata.yml"),
+        );
+        presets.insert("token-account", include_str!("../presets/token-account.yml"));
+        presets
+    };
+}
+
+lazy_static! {
+    /// Fragment name -> packaged YAML Data Section, embedded with 'type: Fragment'
+    static ref SAD_FRAGMENTS: HashMap<&'static str, &'static str> = {
+        let mut fragments = HashMap::<&str, &str>::new();
+        fragments.insert("clock", include_str!("../presets/fragments/clock.yml"));
+        fragments
+    };
+}
+
+/// Loads the packaged Data Section for a fragment name
+pub fn fragment_descriptor(name: &str) -> Option<Yaml> {
+    SAD_FRAGMENTS
+        .get(name)
+        .map(|decl| YamlLoader::load_from_str(decl).unwrap().remove(0))
+}
+
+/// Loads the packaged descriptor for a preset name
+pub fn preset_descriptor(name: &str) -> Option<Vec<Yaml>> {
+    SAD_PRESETS
+        .get(name)
+        .map(|decl| YamlLoader::load_from_str(decl).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::desertree::Deseriaizer;
+    use base64::decode;
+
+    // Captured StakeStateV2::Stake account data
+    const STAKE_ACCOUNT: &str = "AgAAAIDVIgAAAAAAh8pH1kRpYrDTgmkCN7tWum2wUNBNZN1lFMuVpmCrdyNFg/3dAPx7LrYzFoUto30Oo0znx5dO4fGxjDdqjoLQ0wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaNtQUgARX1+9agW56p5y/AND4Spr3XLxaKBqzgY2GFAPIFKgEAAAD0AQAAAAAAAP//////////AAAAAAAA0D85MAAAAAAAAAAAAAA=";
+    // Captured VoteStateVersions::Current account data, trailing padding trimmed
+    const VOTE_ACCOUNT: &str = "AgAAAIfKR9ZEaWKw04JpAje7VrptsFDQTWTdZRTLlaZgq3cjRYP93QD8ey62MxaFLaN9DqNM58eXTuHxsYw3ao6C0NMHAgAAAAAAAAAAZAAAAAAAAAACAAAAAGUAAAAAAAAAAQAAAAABAAAAAAAAAFgCAAAAAAAABo21BSABFfX71qBbnqnnL8A0PhKmvdcvFooGrOBjYYUAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAfAAAAAAAAAAEBAAAAAAAAAFgCAAAAAAAAAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
+    // Token Metadata (MetadataV1) account data, name/symbol/uri padded and the account
+    // zero filled to its allocated size as the program writes them
+    const METADATA_ACCOUNT: &str = "BIfKR9ZEaWKw04JpAje7VrptsFDQTWTdZRTLlaZgq3cjBo21BSABFfX71qBbnqnnL8A0PhKmvdcvFooGrOBjYYUgAAAAR2FkZ2V0ICMxAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAKAAAAR0RHVAAAAAAAAMgAAABodHRwczovL2Fyd2VhdmUubmV0L2dhZGdldC0xLmpzb24AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAPQBAQIAAACHykfWRGlisNOCaQI3u1a6bbBQ0E1k3WUUy5WmYKt3IwEABo21BSABFfX71qBbnqnnL8A0PhKmvdcvFooGrOBjYYUAZAEBAf4BAAEBBt324ddloZPZy+FGzut5rBy0he1fWzeROoz1hX7/AKkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==";
+
+    #[test]
+    fn test_presets_load_pass() {
+        for name in SAD_PRESET_NAMES {
+            let decl = preset_descriptor(name).unwrap();
+            Deseriaizer::new(&decl[0]);
+        }
+        assert!(preset_descriptor("nothere").is_none());
+    }
+
+    #[test]
+    fn test_clock_fragment_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Timed:\n  - owner:\n      type: PublicKey\n  - clock:\n      type: Fragment\n      name: clock\n  - done:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let owner = solana_sdk::pubkey::Pubkey::new_unique();
+        let mut data = owner.to_bytes().to_vec();
+        data.extend(250_000_000u64.to_le_bytes());
+        data.extend(1_699_900_000i64.to_le_bytes());
+        data.extend(580u64.to_le_bytes());
+        data.extend(581u64.to_le_bytes());
+        data.extend(1_700_000_000i64.to_le_bytes());
+        data.push(1);
+        let result = desc.to_json(&desc.deser(&mut data.as_slice()).unwrap());
+        assert_eq!(
+            result,
+            serde_json::json!({
+                "owner": owner.to_string(),
+                "clock": {
+                    "slot": 250_000_000u64,
+                    "epoch_start_timestamp": "2023-11-13T18:26:40Z",
+                    "epoch": 580,
+                    "leader_schedule_epoch": 581,
+                    "unix_timestamp": "2023-11-14T22:13:20Z",
+                },
+                "done": true,
+            })
+        );
+        assert!(fragment_descriptor("nothere").is_none());
+    }
+
+    #[test]
+    fn test_stake_preset_pass() {
+        let data = decode(STAKE_ACCOUNT).unwrap();
+        let desc = Deseriaizer::new(&preset_descriptor("stake").unwrap()[0]);
+        let result = desc.to_json(&desc.deser(&mut data.as_slice()).unwrap());
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+        let stake = &result["state"]["Stake"];
+        assert_eq!(stake[0]["rent_exempt_reserve"], 2282880);
+        assert_eq!(
+            stake[0]["authorized"]["staker"],
+            "A94wMjV54C8f8wn7zL8TxNCdNiGoq7XSN7vWGrtd4vwU"
+        );
+        assert_eq!(
+            stake[1]["delegation"]["voter_pubkey"],
+            "SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv"
+        );
+        assert_eq!(stake[1]["delegation"]["stake"], 5_000_000_000u64);
+        assert_eq!(stake[1]["delegation"]["deactivation_epoch"], u64::MAX);
+        assert_eq!(stake[1]["credits_observed"], 12345);
+        assert_eq!(stake[2], 0);
+    }
+
+    #[test]
+    fn test_vote_preset_pass() {
+        let data = decode(VOTE_ACCOUNT).unwrap();
+        let desc = Deseriaizer::new(&preset_descriptor("vote").unwrap()[0]);
+        let result = desc.to_json(&desc.deser(&mut data.as_slice()).unwrap());
+        let vote = &result["state"]["Current"];
+        assert_eq!(
+            vote["node_pubkey"],
+            "A94wMjV54C8f8wn7zL8TxNCdNiGoq7XSN7vWGrtd4vwU"
+        );
+        assert_eq!(vote["commission"], 7);
+        assert_eq!(vote["votes"].as_array().unwrap().len(), 2);
+        assert_eq!(vote["votes"][0]["lockout"]["slot"], 100);
+        assert_eq!(vote["votes"][1]["lockout"]["confirmation_count"], 1);
+        assert_eq!(vote["root_slot"], serde_json::Value::Null);
+        assert_eq!(
+            vote["authorized_voters"]["600"],
+            "SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv"
+        );
+        assert_eq!(vote["prior_voters"]["buf"].as_array().unwrap().len(), 32);
+        assert_eq!(vote["prior_voters"]["is_empty"], true);
+        assert_eq!(vote["epoch_credits"][0], serde_json::json!([600, 2, 0]));
+    }
+
+    #[test]
+    fn test_metaplex_metadata_preset_pass() {
+        let data = decode(METADATA_ACCOUNT).unwrap();
+        let desc = Deseriaizer::new(&preset_descriptor("metaplex-metadata").unwrap()[0]);
+        let result = desc.to_json(&desc.deser(&mut data.as_slice()).unwrap());
+        let unpad = |v: &serde_json::Value| v.as_str().unwrap().trim_end_matches('\0').to_string();
+        assert_eq!(result["key"], "MetadataV1");
+        assert_eq!(
+            result["mint"],
+            "SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv"
+        );
+        assert_eq!(unpad(&result["data"]["name"]), "Gadget #1");
+        assert_eq!(unpad(&result["data"]["symbol"]), "GDGT");
+        assert_eq!(
+            unpad(&result["data"]["uri"]),
+            "https://arweave.net/gadget-1.json"
+        );
+        assert_eq!(result["data"]["seller_fee_basis_points"], 500);
+        let creators = result["data"]["creators"].as_array().unwrap();
+        assert_eq!(creators.len(), 2);
+        assert_eq!(
+            creators[0]["address"],
+            "A94wMjV54C8f8wn7zL8TxNCdNiGoq7XSN7vWGrtd4vwU"
+        );
+        assert_eq!(creators[0]["verified"], true);
+        assert_eq!(creators[1]["share"], 100);
+        assert_eq!(result["edition_nonce"], 254);
+        assert_eq!(result["token_standard"], "NonFungible");
+        assert_eq!(
+            result["collection"]["key"],
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        );
+        assert_eq!(result["uses"], serde_json::Value::Null);
+        assert_eq!(result["programmable_config"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_token_account_preset_pass() {
+        let (mint, owner, delegate) = (
+            solana_sdk::pubkey::Pubkey::new_unique(),
+            solana_sdk::pubkey::Pubkey::new_unique(),
+            solana_sdk::pubkey::Pubkey::new_unique(),
+        );
+        let mut data = mint.to_bytes().to_vec();
+        data.extend(owner.to_bytes());
+        data.extend(1_500_000u64.to_le_bytes());
+        data.extend(1u32.to_le_bytes());
+        data.extend(delegate.to_bytes());
+        data.push(1);
+        data.extend([0u8; 12]);
+        data.extend(500_000u64.to_le_bytes());
+        data.extend([0u8; 36]);
+        assert_eq!(data.len(), 165);
+        let desc = Deseriaizer::new(&preset_descriptor("token-account").unwrap()[0]);
+        let result = desc.to_json(&desc.deser(&mut data.as_slice()).unwrap());
+        assert_eq!(result["mint"], mint.to_string());
+        assert_eq!(result["owner"], owner.to_string());
+        assert_eq!(result["amount"], 1_500_000);
+        assert_eq!(result["delegate_tag"], 1);
+        assert_eq!(result["delegate"], delegate.to_string());
+        assert_eq!(result["state"], "Initialized");
+        assert_eq!(result["is_native_tag"], 0);
+        assert_eq!(result["delegated_amount"], 500_000);
+        assert_eq!(result["close_authority_tag"], 0);
+    }
+}
@@ -1,7 +1,11 @@
 //! @brief sad outputs
 
-use crate::{desertree::Deseriaizer, errors::SadApplicationResult, solq::DeserializationResult};
-use serde_json::{from_str, json, to_string_pretty};
+use crate::{
+    desertree::Deseriaizer,
+    errors::{SadAppErrorType, SadApplicationResult},
+    solq::{AccountResultContext, DeserializationResult},
+};
+use serde_json::{from_str, json, to_string_pretty, Map, Value};
 use std::{
     fs::{read_to_string, File},
     io::Write,
@@ -14,6 +18,25 @@ pub trait SadOutput: std::fmt::Debug {
     fn write(&self) -> SadApplicationResult<()>;
 }
 
+/// Adds the rent exemption, if fetched, to an account's output
+fn insert_rent(jmap_raw: &mut Map<String, Value>, context: &AccountResultContext) {
+    if let Some(rent) = context.rent() {
+        jmap_raw.insert("rent_exempt".to_string(), json!(rent.exempt()));
+        jmap_raw.insert(
+            "rent_exempt_minimum".to_string(),
+            json!(rent.minimum_balance()),
+        );
+    }
+}
+
+/// Adds the UI amount, if fetched, to a token account's output,
+/// null when its mint could not be fetched
+fn insert_ui_amount(jmap_raw: &mut Map<String, Value>, context: &AccountResultContext) {
+    if let Some(token_amount) = context.token_amount() {
+        jmap_raw.insert("ui_amount".to_string(), json!(token_amount.ui_amount()));
+    }
+}
+
 /// Pretty prints output to sysout
 #[derive(Debug)]
 pub struct SadSysOutput {
@@ -48,6 +71,8 @@ impl SadOutput for SadSysOutput {
                 "account_program_key".to_string(),
                 json!(blocks.account().owner.to_string()),
             );
+            insert_rent(jmap_raw, blocks);
+            insert_ui_amount(jmap_raw, blocks);
             jmap_raw.insert(
                 "data".to_string(),
                 self.deser.to_json(blocks.deserialize_list()),
@@ -70,6 +95,7 @@ pub struct SadJsonOutput {
     dresult: DeserializationResult,
     deser: Deseriaizer,
     file_name: String,
+    numbers_as_strings: bool,
 }
 
 impl SadJsonOutput {
@@ -78,8 +104,16 @@ impl SadJsonOutput {
             dresult: data,
             deser: decl,
             file_name: out_file.to_string(),
+            numbers_as_strings: false,
         }
     }
+
+    /// Renders integer fields as strings, preserving precision for
+    /// consumers limited to 2^53 (e.g. JavaScript)
+    pub fn with_numbers_as_strings(mut self, numbers_as_strings: bool) -> Self {
+        self.numbers_as_strings = numbers_as_strings;
+        self
+    }
 }
 
 impl SadOutput for SadJsonOutput {
@@ -99,7 +133,17 @@ impl SadOutput for SadJsonOutput {
                 "account_program_key".to_string(),
                 json!(c.account().owner.to_string()),
             );
-            jmap_raw.insert("data".to_string(), self.deser.to_json(c.deserialize_list()));
+            insert_rent(jmap_raw, c);
+            insert_ui_amount(jmap_raw, c);
+            let data = self.deser.to_json(c.deserialize_list());
+            jmap_raw.insert(
+                "data".to_string(),
+                if self.numbers_as_strings {
+                    numbers_to_strings(data)
+                } else {
+                    data
+                },
+            );
             json_vector.as_array_mut().unwrap().push(jmap);
         }
         let ppjson = to_string_pretty(&json_vector).unwrap();
@@ -115,6 +159,260 @@ impl SadOutput for SadJsonOutput {
     }
 }
 
+/// Prints output to sysout as flattened `path = value` lines
+#[derive(Debug)]
+pub struct SadFlatOutput {
+    deser: Deseriaizer,
+    dresult: DeserializationResult,
+}
+
+impl SadFlatOutput {
+    pub fn new(data: DeserializationResult, ddecl: Deseriaizer) -> Self {
+        Self {
+            deser: ddecl,
+            dresult: data,
+        }
+    }
+}
+
+impl SadOutput for SadFlatOutput {
+    fn write(&self) -> SadApplicationResult<()> {
+        for (index, blocks) in self
+            .deserialization_result()
+            .context_vec()
+            .iter()
+            .enumerate()
+        {
+            if index > 0 {
+                println!();
+            }
+            println!("account_key = {}", blocks.pubkey());
+            println!("account_program_key = {}", blocks.account().owner);
+            if let Some(rent) = blocks.rent() {
+                println!("rent_exempt = {}", rent.exempt());
+                println!("rent_exempt_minimum = {}", rent.minimum_balance());
+            }
+            if let Some(token_amount) = blocks.token_amount() {
+                println!("ui_amount = {}", json!(token_amount.ui_amount()));
+            }
+            for line in flatten_json(&self.deser.to_json(blocks.deserialize_list())) {
+                println!("{}", line);
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialization_result(&self) -> &DeserializationResult {
+        &self.dresult
+    }
+}
+
+/// Replaces integer numbers in JSON with their string rendering
+pub fn numbers_to_strings(value: Value) -> Value {
+    match value {
+        Value::Number(n) if n.is_u64() || n.is_i64() => Value::String(n.to_string()),
+        Value::Array(array) => Value::Array(array.into_iter().map(numbers_to_strings).collect()),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| (k, numbers_to_strings(v)))
+                .collect(),
+        ),
+        _ => value,
+    }
+}
+
+/// Flattens JSON into `path = value` lines where nested objects
+/// are joined with '.' and array elements are indexed as `[n]`
+pub fn flatten_json(value: &Value) -> Vec<String> {
+    let mut lines = Vec::<String>::new();
+    flatten_json_into("", value, &mut lines);
+    lines
+}
+
+fn flatten_json_into(path: &str, value: &Value, lines: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, v) in map {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten_json_into(&child, v, lines)
+            }
+        }
+        Value::Array(array) if !array.is_empty() => {
+            for (index, v) in array.iter().enumerate() {
+                flatten_json_into(&format!("{}[{}]", path, index), v, lines)
+            }
+        }
+        Value::String(s) => lines.push(format!("{} = {}", path, s)),
+        _ => lines.push(format!("{} = {}", path, value)),
+    }
+}
+
+/// Prints the fields that differ between two accounts to sysout
+/// as `path: old -> new` lines
+#[derive(Debug)]
+pub struct SadDiffOutput {
+    deser: Deseriaizer,
+    dresult: DeserializationResult,
+    other: DeserializationResult,
+}
+
+impl SadDiffOutput {
+    pub fn new(
+        data: DeserializationResult,
+        other: DeserializationResult,
+        ddecl: Deseriaizer,
+    ) -> Self {
+        Self {
+            deser: ddecl,
+            dresult: data,
+            other,
+        }
+    }
+}
+
+impl SadOutput for SadDiffOutput {
+    fn write(&self) -> SadApplicationResult<()> {
+        let (a, b) = (
+            &self.deserialization_result().context_vec()[0],
+            &self.other.context_vec()[0],
+        );
+        println!("--- {}", a.pubkey());
+        println!("+++ {}", b.pubkey());
+        let changes = diff_json(
+            &self.deser.to_json(a.deserialize_list()),
+            &self.deser.to_json(b.deserialize_list()),
+        );
+        if changes.is_empty() {
+            println!("No fields differ");
+        }
+        let render = |value: &Option<Value>| match value {
+            Some(Value::String(s)) => s.clone(),
+            Some(value) => value.to_string(),
+            None => "(absent)".to_string(),
+        };
+        for (path, old, new) in changes {
+            println!("{}: {} -> {}", path, render(&old), render(&new));
+        }
+        Ok(())
+    }
+
+    fn deserialization_result(&self) -> &DeserializationResult {
+        &self.dresult
+    }
+}
+
+/// The paths, as for flatten_json, of the values that differ between 'a' and 'b'
+/// with the value on each side, None where a side does not have the path
+pub fn diff_json(a: &Value, b: &Value) -> Vec<(String, Option<Value>, Option<Value>)> {
+    let mut changes = Vec::<(String, Option<Value>, Option<Value>)>::new();
+    diff_json_into("", Some(a), Some(b), &mut changes);
+    changes
+}
+
+fn diff_json_into(
+    path: &str,
+    a: Option<&Value>,
+    b: Option<&Value>,
+    changes: &mut Vec<(String, Option<Value>, Option<Value>)>,
+) {
+    match (a, b) {
+        (Some(Value::Object(ma)), Some(Value::Object(mb))) => {
+            let mut keys: Vec<&String> = ma.keys().chain(mb.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_json_into(&child, ma.get(key), mb.get(key), changes)
+            }
+        }
+        (Some(Value::Array(va)), Some(Value::Array(vb))) => {
+            for index in 0..va.len().max(vb.len()) {
+                diff_json_into(
+                    &format!("{}[{}]", path, index),
+                    va.get(index),
+                    vb.get(index),
+                    changes,
+                )
+            }
+        }
+        _ if a != b => changes.push((path.to_string(), a.cloned(), b.cloned())),
+        _ => {}
+    }
+}
+
+/// Prints only the value selected by a path to sysout, one per account
+#[derive(Debug)]
+pub struct SadSelectOutput {
+    deser: Deseriaizer,
+    dresult: DeserializationResult,
+    path: String,
+}
+
+impl SadSelectOutput {
+    pub fn new(data: DeserializationResult, ddecl: Deseriaizer, path: &str) -> Self {
+        Self {
+            deser: ddecl,
+            dresult: data,
+            path: path.to_string(),
+        }
+    }
+}
+
+impl SadOutput for SadSelectOutput {
+    fn write(&self) -> SadApplicationResult<()> {
+        for blocks in self.deserialization_result().context_vec() {
+            let json = self.deser.to_json(blocks.deserialize_list());
+            match select_json(&json, &self.path)? {
+                Value::String(s) => println!("{}", s),
+                value => println!("{}", value),
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialization_result(&self) -> &DeserializationResult {
+        &self.dresult
+    }
+}
+
+/// Navigates JSON by a path of '.' separated keys where array
+/// elements are indexed as `[n]`, e.g. `owner.authority` or `votes[0].slot`
+pub fn select_json<'a>(value: &'a Value, path: &str) -> SadApplicationResult<&'a Value> {
+    let not_found = || SadAppErrorType::SelectPathNotFound(path.to_string());
+    let mut current = value;
+    for segment in path.split('.') {
+        let (key, mut indices) = match segment.find('[') {
+            Some(pos) => segment.split_at(pos),
+            None => (segment, ""),
+        };
+        if !key.is_empty() {
+            current = current.get(key).ok_or_else(not_found)?;
+        } else if indices.is_empty() {
+            return Err(not_found());
+        }
+        while !indices.is_empty() {
+            let close = indices.find(']').ok_or_else(not_found)?;
+            let index = indices[1..close]
+                .parse::<usize>()
+                .map_err(|_| not_found())?;
+            current = current.get(index).ok_or_else(not_found)?;
+            indices = &indices[close + 1..];
+            if !indices.is_empty() && !indices.starts_with('[') {
+                return Err(not_found());
+            }
+        }
+    }
+    Ok(current)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -242,4 +540,117 @@ mod tests {
         let data = mhmap.try_to_vec().unwrap();
         write(&desc.deser(&mut data.as_slice()).unwrap());
     }
+
+    #[test]
+    fn test_flatten_json_pass() {
+        let docs = yaml_rust::YamlLoader::load_from_str(
+            "Flat:\n  - owner:\n      type: CStruct\n      fields:\n        - type: NamedField\n          descriptor:\n            name: authority\n            contains:\n              type: PublicKey\n        - type: NamedField\n          descriptor:\n            name: amounts\n            contains:\n              type: Vec\n              contains:\n                - type: U8\n  - empty:\n      type: Vec\n      contains:\n        - type: U8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let authority = solana_sdk::pubkey::Pubkey::new_unique();
+        let mut data = authority.to_bytes().to_vec();
+        data.extend(vec![7u8, 9].try_to_vec().unwrap());
+        data.extend(Vec::<u8>::new().try_to_vec().unwrap());
+        let lines = flatten_json(&desc.to_json(&desc.deser(&mut data.as_slice()).unwrap()));
+        assert_eq!(
+            lines,
+            vec![
+                "empty = []".to_string(),
+                "owner.amounts[0] = 7".to_string(),
+                "owner.amounts[1] = 9".to_string(),
+                format!("owner.authority = {}", authority),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numbers_to_strings_pass() {
+        let docs = yaml_rust::YamlLoader::load_from_str(
+            "Large:\n  - lamports:\n      type: U64\n  - delta:\n      type: I64\n  - ratio:\n      type: F64\n  - votes:\n      type: Vec\n      contains:\n        - type: U8\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let mut data = u64::MAX.try_to_vec().unwrap();
+        data.extend((-5i64).try_to_vec().unwrap());
+        data.extend(0.5f64.try_to_vec().unwrap());
+        data.extend(vec![7u8].try_to_vec().unwrap());
+        let result = desc.deser(&mut data.as_slice()).unwrap();
+        assert_eq!(
+            numbers_to_strings(desc.to_json(&result)),
+            json!({
+                "lamports": "18446744073709551615",
+                "delta": "-5",
+                "ratio": 0.5,
+                "votes": ["7"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_json_pass() {
+        let value = json!({
+            "owner": {"authority": "SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv"},
+            "votes": [{"slot": 1}, {"slot": 2}],
+            "matrix": [[1, 2], [3, 4]]
+        });
+        assert_eq!(
+            select_json(&value, "owner.authority").unwrap(),
+            &json!("SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv")
+        );
+        assert_eq!(select_json(&value, "votes[1].slot").unwrap(), &json!(2));
+        assert_eq!(select_json(&value, "matrix[1][0]").unwrap(), &json!(3));
+        assert_eq!(
+            select_json(&value, "votes[0]").unwrap(),
+            &json!({"slot": 1})
+        );
+    }
+
+    #[test]
+    fn test_diff_json_pass() {
+        let docs = yaml_rust::YamlLoader::load_from_str(
+            "Diffed:\n  - owner:\n      type: CStruct\n      fields:\n        - type: NamedField\n          descriptor:\n            name: authority\n            contains:\n              type: PublicKey\n        - type: NamedField\n          descriptor:\n            name: amounts\n            contains:\n              type: Vec\n              contains:\n                - type: U8\n  - flag:\n      type: Bool\n",
+        )
+        .unwrap();
+        let desc = Deseriaizer::new(&docs[0]);
+        let authority = solana_sdk::pubkey::Pubkey::new_unique();
+        let encode = |amounts: Vec<u8>| {
+            let mut data = authority.to_bytes().to_vec();
+            data.extend(amounts.try_to_vec().unwrap());
+            data.push(1);
+            desc.to_json(&desc.deser(&mut data.as_slice()).unwrap())
+        };
+        let (before, after) = (encode(vec![7, 9]), encode(vec![7, 10]));
+        assert_eq!(
+            diff_json(&before, &after),
+            vec![(
+                "owner.amounts[1]".to_string(),
+                Some(json!(9)),
+                Some(json!(10))
+            )]
+        );
+        assert!(diff_json(&before, &before).is_empty());
+        assert_eq!(
+            diff_json(&before, &encode(vec![7])),
+            vec![("owner.amounts[1]".to_string(), Some(json!(9)), None)]
+        );
+    }
+
+    #[test]
+    fn test_select_json_not_found() {
+        let value = json!({"owner": {"authority": "x"}, "votes": [{"slot": 1}]});
+        for path in [
+            "owner.missing",
+            "votes[3]",
+            "votes[x]",
+            "owner[0]",
+            "votes[0",
+            "",
+        ] {
+            assert!(matches!(
+                select_json(&value, path),
+                Err(SadAppErrorType::SelectPathNotFound(p)) if p == path
+            ));
+        }
+    }
 }
@@ -51,6 +51,10 @@ pub enum SadValue {
     NamedField(Vec<SadValue>),
     #[strum(props(Type = "Scalar"))]
     PublicKey(Pubkey),
+    #[strum(props(Type = "Compound"))]
+    Option(Vec<SadValue>),
+    #[strum(props(Type = "Compound"))]
+    Enum(Vec<SadValue>),
 }
 
 pub fn is_sadvalue_type(in_str: &str) -> bool {
@@ -101,6 +105,65 @@ pub fn from_scalar_value_for(intype: &SadValue) -> String {
     }
 }
 
+/// Converts an unsigned length or tag value to usize
+pub fn to_usize_for(intype: &SadValue) -> Option<usize> {
+    match intype {
+        SadValue::U8(item) => Some(*item as usize),
+        SadValue::U16(item) => Some(*item as usize),
+        SadValue::U32(item) => Some(*item as usize),
+        SadValue::U64(item) => usize::try_from(*item).ok(),
+        _ => None,
+    }
+}
+
+/// Converts an integer value to i128, None for non integers
+/// and u128 values beyond the i128 range
+pub fn to_i128_for(intype: &SadValue) -> Option<i128> {
+    match intype {
+        SadValue::U8(item) => Some(*item as i128),
+        SadValue::U16(item) => Some(*item as i128),
+        SadValue::U32(item) => Some(*item as i128),
+        SadValue::U64(item) => Some(*item as i128),
+        SadValue::U128(item) => i128::try_from(*item).ok(),
+        SadValue::I8(item) => Some(*item as i128),
+        SadValue::I16(item) => Some(*item as i128),
+        SadValue::I32(item) => Some(*item as i128),
+        SadValue::I64(item) => Some(*item as i128),
+        SadValue::I128(item) => Some(*item),
+        _ => None,
+    }
+}
+
+/// Converts a scalar value to its little endian bytes, e.g. for PDA seeds
+pub fn to_seed_bytes_for(intype: &SadValue) -> Option<Vec<u8>> {
+    match intype {
+        SadValue::String(item) => Some(item.as_bytes().to_vec()),
+        SadValue::Bool(item) => Some(vec![*item as u8]),
+        SadValue::U8(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::U16(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::U32(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::U64(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::U128(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::I8(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::I16(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::I32(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::I64(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::I128(item) => Some(item.to_le_bytes().to_vec()),
+        SadValue::PublicKey(item) => Some(item.to_bytes().to_vec()),
+        _ => None,
+    }
+}
+
+/// Converts a numeric value to f64
+pub fn to_f64_for(intype: &SadValue) -> Option<f64> {
+    match intype {
+        SadValue::F32(item) => Some(*item as f64),
+        SadValue::F64(item) => Some(*item),
+        SadValue::U128(item) => Some(*item as f64),
+        _ => to_i128_for(intype).map(|v| v as f64),
+    }
+}
+
 pub trait SadElement {
     fn deser(buf: &mut &[u8]) -> SadValue;
 }
@@ -208,7 +271,7 @@ impl SadElement for i128 {
 }
 impl SadElement for f32 {
     fn deser(buf: &mut &[u8]) -> SadValue {
-        let st = f32::try_from_slice(buf).unwrap();
+        let st = f32::try_from_slice(&buf[..4]).unwrap();
         *buf = &buf[4..];
         SadValue::F32(st)
     }
@@ -216,7 +279,7 @@ impl SadElement for f32 {
 
 impl SadElement for f64 {
     fn deser(buf: &mut &[u8]) -> SadValue {
-        let st = f64::try_from_slice(buf).unwrap();
+        let st = f64::try_from_slice(&buf[..8]).unwrap();
         *buf = &buf[8..];
         SadValue::F64(st)
     }
@@ -224,7 +287,7 @@ impl SadElement for f64 {
 
 impl SadElement for Pubkey {
     fn deser(buf: &mut &[u8]) -> SadValue {
-        let st = Pubkey::try_from_slice(buf).unwrap();
+        let st = Pubkey::try_from_slice(&buf[..32]).unwrap();
         *buf = &buf[32..];
         SadValue::PublicKey(st)
     }
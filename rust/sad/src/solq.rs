@@ -2,15 +2,18 @@
 
 use {
     crate::{
-        desertree::Deseriaizer,
+        desertree::{Deseriaizer, SadAccountResolver},
         errors::{SadAccountErrorType, SadAccountResult},
         sadtypes::SadValue,
     },
+    gadgets_common::RetryPolicy,
+    rayon::prelude::*,
     solana_client::rpc_client::RpcClient,
     solana_sdk::{
         account::{Account, ReadableAccount},
         pubkey::Pubkey,
     },
+    std::collections::HashMap,
 };
 
 /// Identifies type of processing for deserialization
@@ -20,12 +23,54 @@ pub enum ResultForKeyType {
     ProgramAccount(Pubkey),
 }
 
+/// Order of program accounts in the results, ties ordered by pubkey
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccountOrder {
+    Pubkey,
+    DataSize,
+}
+
+/// Rent exemption of an account for its data size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RentStatus {
+    minimum_balance: u64,
+    exempt: bool,
+}
+
+impl RentStatus {
+    pub fn minimum_balance(&self) -> u64 {
+        self.minimum_balance
+    }
+
+    pub fn exempt(&self) -> bool {
+        self.exempt
+    }
+}
+
+/// Amount held by an SPL token account with its mint's decimals, None
+/// if the mint could not be fetched
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenAmount {
+    amount: u64,
+    decimals: Option<u8>,
+}
+
+impl TokenAmount {
+    /// Amount in whole tokens, None if the mint could not be fetched
+    pub fn ui_amount(&self) -> Option<f64> {
+        self.decimals
+            .map(|decimals| self.amount as f64 / 10f64.powi(decimals as i32))
+    }
+}
+
 /// Context of deserialization
 #[derive(Debug)]
 pub struct AccountResultContext {
     key: Pubkey,
     account: Account,
     deserialized: Vec<SadValue>,
+    rent: Option<RentStatus>,
+    token_amount: Option<TokenAmount>,
 }
 
 impl AccountResultContext {
@@ -34,9 +79,21 @@ impl AccountResultContext {
             key: pkey,
             account: acc,
             deserialized: deser,
+            rent: None,
+            token_amount: None,
         }
     }
 
+    /// Rent exemption, if fetched
+    pub fn rent(&self) -> Option<&RentStatus> {
+        self.rent.as_ref()
+    }
+
+    /// Token amount, if fetched for an SPL token account
+    pub fn token_amount(&self) -> Option<&TokenAmount> {
+        self.token_amount.as_ref()
+    }
+
     pub fn pubkey(&self) -> &Pubkey {
         &self.key
     }
@@ -69,7 +126,81 @@ impl DeserializationResult {
     pub fn context_vec(&self) -> &Vec<AccountResultContext> {
         &self.contexts
     }
+
+    /// Fetches the rent exemption minimum for each account's data size
+    /// and records whether the account balance meets it
+    pub fn fetch_rent(&mut self, rpc_client: &RpcClient) -> SadAccountResult<()> {
+        let mut minimums = HashMap::<usize, u64>::new();
+        for context in &mut self.contexts {
+            let data_len = context.account.data.len();
+            let minimum_balance = match minimums.get(&data_len) {
+                Some(minimum) => *minimum,
+                None => {
+                    let minimum = rpc_client
+                        .get_minimum_balance_for_rent_exemption(data_len)
+                        .map_err(|e| {
+                            eprintln!("{}", e);
+                            SadAccountErrorType::FailedRentGet
+                        })?;
+                    minimums.insert(data_len, minimum);
+                    minimum
+                }
+            };
+            context.rent = Some(RentStatus {
+                minimum_balance,
+                exempt: context.account.lamports >= minimum_balance,
+            });
+        }
+        Ok(())
+    }
+
+    /// Fetches the mint of each SPL token account for its decimals and records
+    /// the account's token amount. A mint that can't be fetched leaves the UI
+    /// amount unresolved rather than failing the output
+    pub fn fetch_ui_amounts(&mut self, rpc_client: &RpcClient) {
+        self.resolve_ui_amounts(|mint| solana_account(rpc_client, mint))
+    }
+
+    /// Records token amounts with mint accounts from 'get_mint', each mint
+    /// fetched once
+    fn resolve_ui_amounts<F>(&mut self, mut get_mint: F)
+    where
+        F: FnMut(&Pubkey) -> SadAccountResult<Account>,
+    {
+        let mut decimals = HashMap::<Pubkey, Option<u8>>::new();
+        for context in &mut self.contexts {
+            let data = context.account.data();
+            if data.len() < SPL_TOKEN_ACCOUNT_AMOUNT_END {
+                continue;
+            }
+            let mint = Pubkey::try_from(&data[..32]).unwrap();
+            let amount = u64::from_le_bytes(
+                data[SPL_TOKEN_ACCOUNT_AMOUNT_END - 8..SPL_TOKEN_ACCOUNT_AMOUNT_END]
+                    .try_into()
+                    .unwrap(),
+            );
+            let mint_decimals = *decimals.entry(mint).or_insert_with(|| {
+                let found = get_mint(&mint)
+                    .ok()
+                    .and_then(|acc| acc.data().get(SPL_MINT_DECIMALS_OFFSET).copied());
+                if found.is_none() {
+                    eprintln!("Mint {} unavailable, ui_amount not resolved", mint);
+                }
+                found
+            });
+            context.token_amount = Some(TokenAmount {
+                amount,
+                decimals: mint_decimals,
+            });
+        }
+    }
 }
+
+/// End of the amount in SPL token account data, after the mint and owner keys
+const SPL_TOKEN_ACCOUNT_AMOUNT_END: usize = 72;
+/// Offset of decimals in SPL mint data, after the mint authority and supply
+const SPL_MINT_DECIMALS_OFFSET: usize = 44;
+
 /// Retrieves a single account from RPC cluster
 ///
 /// Presumes that the key is a program owned account
@@ -86,22 +217,23 @@ pub fn solana_account(rpc_client: &RpcClient, key: &Pubkey) -> SadAccountResult<
 /// Retrieves a list of accounts from RPC cluster
 ///
 /// Presumes that the key is the Program key for which
-/// multiple Program Owned Accounts exist
+/// multiple Program Owned Accounts exist. The scan is
+/// retried per 'retry' as it is prone to fail under load
 pub fn solana_program_accounts(
     rpc_client: &RpcClient,
     key: &Pubkey,
+    retry: &RetryPolicy,
 ) -> SadAccountResult<Vec<(Pubkey, Account)>> {
     let vaccount = solana_account(rpc_client, key)?;
     if vaccount.executable() != true {
         return Err(SadAccountErrorType::NotProgramKeyError);
     }
-    match rpc_client.get_program_accounts(key) {
-        Ok(vec) => Ok(vec),
-        Err(e) => {
+    retry.retry(|attempt| {
+        rpc_client.get_program_accounts(key).map_err(|e| {
             eprintln!("{}", e);
-            Err(SadAccountErrorType::FailedProgramAccountGet)
-        }
-    }
+            SadAccountErrorType::FailedProgramAccountGet(attempt)
+        })
+    })
 }
 
 /// Deserialize a single Account
@@ -115,7 +247,8 @@ pub fn deserialize_account(
         return Err(SadAccountErrorType::AccountIsExecutableError);
     }
     let mut resvec = Vec::<AccountResultContext>::new();
-    match destree.deser(&mut solacc.data()) {
+    let resolver = |k: &Pubkey| solana_account(rpc_client, k).ok();
+    match destree.deser_following(&mut solacc.data(), solacc.owner(), &resolver) {
         Ok(res) => {
             resvec.push(AccountResultContext::new(key.clone(), solacc, res));
             Ok(DeserializationResult {
@@ -127,34 +260,159 @@ pub fn deserialize_account(
     }
 }
 
+/// Deserialize two accounts with the same descriptor, e.g. to diff them
+pub fn deserialize_account_pair(
+    rpc_client: &RpcClient,
+    key_a: &Pubkey,
+    key_b: &Pubkey,
+    destree: &Deseriaizer,
+) -> SadAccountResult<(DeserializationResult, DeserializationResult)> {
+    Ok((
+        deserialize_account(rpc_client, key_a, destree)?,
+        deserialize_account(rpc_client, key_b, destree)?,
+    ))
+}
+
+/// Deserialize accounts across a pool of 'threads' (rayon's default when 0)
+/// with the results sorted by 'order', FollowRef accounts fetched by 'resolver'
+pub fn deserialize_accounts(
+    mut accounts: Vec<(Pubkey, Account)>,
+    destree: &Deseriaizer,
+    resolver: &dyn SadAccountResolver,
+    order: AccountOrder,
+    threads: usize,
+) -> SadAccountResult<Vec<AccountResultContext>> {
+    match order {
+        AccountOrder::Pubkey => accounts.sort_by_key(|a| a.0),
+        AccountOrder::DataSize => accounts.sort_by_key(|a| (a.1.data.len(), a.0)),
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|_| SadAccountErrorType::ThreadPoolSetupFail(threads))?;
+    // Indexed parallel iterators collect in their original order
+    pool.install(|| {
+        accounts
+            .into_par_iter()
+            .map(|(key, account)| {
+                match destree.deser_following(&mut account.data(), account.owner(), resolver) {
+                    Ok(res) => Ok(AccountResultContext::new(key, account, res)),
+                    Err(e) => Err(SadAccountErrorType::FailedAccountDeserialize(
+                        key.to_string(),
+                        e.to_string(),
+                    )),
+                }
+            })
+            .collect()
+    })
+}
+
+/// How well a candidate descriptor decoded an account
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifyMatch {
+    name: String,
+    consumed: usize,
+    data_len: usize,
+    checks_passed: usize,
+}
+
+impl IdentifyMatch {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Bytes of account data the descriptor consumed
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Discriminator checks the account data passed
+    pub fn checks_passed(&self) -> usize {
+        self.checks_passed
+    }
+
+    /// Fraction of the account data consumed, 1.0 when it was decoded entirely
+    pub fn confidence(&self) -> f64 {
+        if self.data_len == 0 {
+            1.0
+        } else {
+            self.consumed as f64 / self.data_len as f64
+        }
+    }
+}
+
+/// Decode an account with each named candidate descriptor, returning those
+/// that decoded cleanly ordered best match first: by confidence, then by checks passed
+pub fn identify_account(
+    account: &Account,
+    candidates: &[(String, Deseriaizer)],
+) -> Vec<IdentifyMatch> {
+    let mut matches: Vec<IdentifyMatch> = candidates
+        .iter()
+        .filter_map(|(name, destree)| {
+            let mut data = account.data();
+            destree.deser_owned(&mut data, account.owner()).ok()?;
+            Some(IdentifyMatch {
+                name: name.clone(),
+                consumed: account.data().len() - data.len(),
+                data_len: account.data().len(),
+                checks_passed: destree.tree().discriminator().map_or(0, |_| 1),
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.confidence()
+            .total_cmp(&a.confidence())
+            .then(b.checks_passed.cmp(&a.checks_passed))
+    });
+    matches
+}
+
+/// Fetch a single account and identify which candidate descriptors decode it
+pub fn identify(
+    rpc_client: &RpcClient,
+    key: &Pubkey,
+    candidates: &[(String, Deseriaizer)],
+) -> SadAccountResult<Vec<IdentifyMatch>> {
+    let solacc = solana_account(rpc_client, key)?;
+    if solacc.executable() {
+        return Err(SadAccountErrorType::AccountIsExecutableError);
+    }
+    Ok(identify_account(&solacc, candidates))
+}
+
 /// Deserialize all Program Owned Accounts
 pub fn deserialize_program_accounts(
     rpc_client: &RpcClient,
     key: &Pubkey,
     destree: &Deseriaizer,
+    retry: &RetryPolicy,
+    order: AccountOrder,
+    threads: usize,
 ) -> SadAccountResult<DeserializationResult> {
-    let solacc = solana_program_accounts(rpc_client, key)?;
-    let mut resvec = Vec::<AccountResultContext>::new();
-    for acc in solacc {
-        // println!("{:?}", encode(acc.1.data()));
-        match destree.deser(&mut acc.1.data()) {
-            Ok(res) => resvec.push(AccountResultContext::new(acc.0.clone(), acc.1, res)),
-            Err(_) => todo!(),
-        }
-    }
+    let solacc = solana_program_accounts(rpc_client, key, retry)?;
     Ok(DeserializationResult {
         account_type: ResultForKeyType::ProgramAccount(key.clone()),
-        contexts: resvec,
+        contexts: deserialize_accounts(
+            solacc,
+            destree,
+            &|k: &Pubkey| solana_account(rpc_client, k).ok(),
+            order,
+            threads,
+        )?,
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
+    use std::{collections::HashMap, str::FromStr, time::Duration};
 
     use gadgets_common::load_yaml_file;
+    use serde_json::json;
     use solana_cli_config::*;
+    use solana_client::rpc_request::RpcRequest;
+    use yaml_rust::YamlLoader;
     // Presume solana-cli-program accounts
     const SCLI: &str = "../../samples/yamldecls/SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv.yml";
 
@@ -174,6 +432,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_resolve_ui_amounts_pass() {
+        let (mint, lost_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let token_account = |mint: &Pubkey, amount: u64| {
+            let mut data = mint.to_bytes().to_vec();
+            data.extend(Pubkey::new_unique().to_bytes());
+            data.extend(amount.to_le_bytes());
+            data.resize(165, 0);
+            Account {
+                data,
+                ..Account::default()
+            }
+        };
+        let mut result = DeserializationResult {
+            account_type: ResultForKeyType::ProgramAccount(Pubkey::new_unique()),
+            contexts: vec![
+                AccountResultContext::new(
+                    Pubkey::new_unique(),
+                    token_account(&mint, 1_234_500),
+                    vec![],
+                ),
+                AccountResultContext::new(
+                    Pubkey::new_unique(),
+                    token_account(&lost_mint, 7),
+                    vec![],
+                ),
+                AccountResultContext::new(Pubkey::new_unique(), Account::default(), vec![]),
+            ],
+        };
+        let mut mint_data = vec![0u8; 82];
+        mint_data[SPL_MINT_DECIMALS_OFFSET] = 6;
+        let mut fetches = 0;
+        result.resolve_ui_amounts(|key| {
+            fetches += 1;
+            if *key == mint {
+                Ok(Account {
+                    data: mint_data.clone(),
+                    ..Account::default()
+                })
+            } else {
+                Err(SadAccountErrorType::FailedAccountGet)
+            }
+        });
+        assert_eq!(fetches, 2);
+        let amounts: Vec<Option<&TokenAmount>> =
+            result.contexts.iter().map(|c| c.token_amount()).collect();
+        assert_eq!(amounts[0].unwrap().decimals, Some(6));
+        assert_eq!(amounts[0].unwrap().ui_amount(), Some(1.2345));
+        assert_eq!(amounts[1].unwrap().amount, 7);
+        assert_eq!(amounts[1].unwrap().ui_amount(), None);
+        assert!(amounts[2].is_none());
+    }
+
     #[test]
     fn test_fetch_pda_pass() {
         let (_, rpc_client) = get_config_rpcclient().unwrap();
@@ -199,7 +510,7 @@ mod tests {
         // Presume solana-cli-program accounts are created and run either locally or devnet
         let pubkey = Pubkey::from_str("SampGgdt3wioaoMZhC6LTSbg4pnuvQnSfJpDYeuXQBv").unwrap();
         // let pubkey = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-        let x = solana_program_accounts(&rpc_client, &pubkey);
+        let x = solana_program_accounts(&rpc_client, &pubkey, &RetryPolicy::default());
         assert!(x.is_ok());
         println!("{:?}", x.unwrap());
     }
@@ -227,7 +538,15 @@ mod tests {
         let twokey = Pubkey::from_str("5gMsBeLmPkwEKQ1H2AwceAPasXLyZ4tvWGCYR59qf47U").unwrap();
         let yamldecl = load_yaml_file(SCLI).unwrap();
         let data_declaration = Deseriaizer::new(&yamldecl[0]);
-        let deser = deserialize_program_accounts(&rpc_client, &pubkey, &data_declaration).unwrap();
+        let deser = deserialize_program_accounts(
+            &rpc_client,
+            &pubkey,
+            &data_declaration,
+            &RetryPolicy::default(),
+            AccountOrder::Pubkey,
+            0,
+        )
+        .unwrap();
         assert_eq!(deser.context_count(), 2);
         assert_eq!(
             deser.account_type(),
@@ -247,4 +566,202 @@ mod tests {
             println!("Data {:?}", c.deserialize_list());
         }
     }
+
+    /// Mocks the program account lookup, 'program_accounts' answers the
+    /// first scan with the default mock answering any that follow
+    fn mock_program_rpcclient(url: &str, program_accounts: serde_json::Value) -> RpcClient {
+        let mut mocks = HashMap::new();
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            json!({
+                "context": {"slot": 1},
+                "value": {
+                    "lamports": 1,
+                    "data": ["", "base64"],
+                    "owner": "BPFLoaderUpgradeab1e11111111111111111111111",
+                    "executable": true,
+                    "rentEpoch": 0,
+                    "space": 0
+                }
+            }),
+        );
+        mocks.insert(RpcRequest::GetProgramAccounts, program_accounts);
+        RpcClient::new_mock_with_mocks(url.to_string(), mocks)
+    }
+
+    #[test]
+    fn test_program_accounts_retry_pass() {
+        let rpc_client = mock_program_rpcclient("succeeds", json!("unavailable"));
+        let docs = YamlLoader::load_from_str(
+            "Empty:\n  - data:\n      type: FillBetween\n      trailer_size: 0\n",
+        )
+        .unwrap();
+        let deser = deserialize_program_accounts(
+            &rpc_client,
+            &Pubkey::new_unique(),
+            &Deseriaizer::new(&docs[0]),
+            &RetryPolicy::new(2, Duration::ZERO),
+            AccountOrder::Pubkey,
+            0,
+        )
+        .unwrap();
+        assert_eq!(deser.context_count(), 1);
+    }
+
+    #[test]
+    fn test_fetch_rent_pass() {
+        let mut mocks = HashMap::new();
+        mocks.insert(RpcRequest::GetMinimumBalanceForRentExemption, json!(1000));
+        let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+        let account = |lamports| Account {
+            lamports,
+            data: vec![0; 16],
+            ..Account::default()
+        };
+        let mut deser = DeserializationResult {
+            account_type: ResultForKeyType::ProgramAccount(Pubkey::new_unique()),
+            contexts: vec![
+                AccountResultContext::new(Pubkey::new_unique(), account(1500), vec![]),
+                AccountResultContext::new(Pubkey::new_unique(), account(500), vec![]),
+            ],
+        };
+        deser.fetch_rent(&rpc_client).unwrap();
+        let rents: Vec<_> = deser
+            .context_vec()
+            .iter()
+            .map(|c| *c.rent().unwrap())
+            .collect();
+        assert_eq!(
+            rents,
+            vec![
+                RentStatus {
+                    minimum_balance: 1000,
+                    exempt: true
+                },
+                RentStatus {
+                    minimum_balance: 1000,
+                    exempt: false
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parallel_deserialize_pass() {
+        let docs = YamlLoader::load_from_str(
+            "Sized:\n  - size:\n      type: U8\n  - data:\n      type: FillBetween\n      trailer_size: 0\n",
+        )
+        .unwrap();
+        let destree = Deseriaizer::new(&docs[0]);
+        let accounts: Vec<(Pubkey, Account)> = (0..64u8)
+            .map(|i| {
+                let size = (i as usize * 7 % 13) as u8;
+                let mut data = vec![size];
+                data.extend(vec![i; size as usize]);
+                (
+                    Pubkey::new_unique(),
+                    Account {
+                        data,
+                        ..Account::default()
+                    },
+                )
+            })
+            .collect();
+        let unresolved = |_: &Pubkey| None;
+        let render = |contexts: Vec<AccountResultContext>| {
+            contexts
+                .iter()
+                .map(|c| (*c.pubkey(), destree.to_json(c.deserialize_list())))
+                .collect::<Vec<_>>()
+        };
+        for order in [AccountOrder::Pubkey, AccountOrder::DataSize] {
+            let serial = render(
+                deserialize_accounts(accounts.clone(), &destree, &unresolved, order, 1).unwrap(),
+            );
+            let parallel = render(
+                deserialize_accounts(accounts.clone(), &destree, &unresolved, order, 4).unwrap(),
+            );
+            assert_eq!(serial, parallel);
+            assert_eq!(serial.len(), accounts.len());
+        }
+        let by_size = render(
+            deserialize_accounts(
+                accounts.clone(),
+                &destree,
+                &unresolved,
+                AccountOrder::DataSize,
+                4,
+            )
+            .unwrap(),
+        );
+        assert!(by_size
+            .windows(2)
+            .all(|w| w[0].1["size"].as_u64() <= w[1].1["size"].as_u64()));
+        let by_key = render(
+            deserialize_accounts(accounts, &destree, &unresolved, AccountOrder::Pubkey, 4).unwrap(),
+        );
+        assert!(by_key.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_identify_account_pass() {
+        let candidate = |yaml: &str| {
+            let docs = YamlLoader::load_from_str(yaml).unwrap();
+            Deseriaizer::new(&docs[0])
+        };
+        let candidates = vec![
+            (
+                "pair".to_string(),
+                candidate("Pair:\n  - first:\n      type: U32\n  - second:\n      type: U32\n"),
+            ),
+            (
+                "single".to_string(),
+                candidate("Single:\n  - value:\n      type: U16\n"),
+            ),
+            (
+                "anchored".to_string(),
+                candidate("Anchored:\n  - value:\n      type: U64\nanchor_account: Counter\n"),
+            ),
+        ];
+        let account = Account {
+            data: vec![1, 0, 0, 0, 2, 0, 0, 0],
+            ..Account::default()
+        };
+        let matches = identify_account(&account, &candidates);
+        // The discriminator of 'anchored' does not match so it is not reported
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].name(), "pair");
+        assert_eq!(matches[0].consumed(), 8);
+        assert_eq!(matches[0].confidence(), 1.0);
+        assert_eq!(matches[1].name(), "single");
+        assert_eq!(matches[1].confidence(), 0.25);
+
+        // A candidate whose Vec prefix starts at the end of the data is rejected
+        let candidates = vec![
+            (
+                "trailing".to_string(),
+                candidate("Trailing:\n  - header:\n      type: U64\n  - items:\n      type: Vec\n      contains:\n        - type: U8\n"),
+            ),
+            (
+                "pair".to_string(),
+                candidate("Pair:\n  - first:\n      type: U32\n  - second:\n      type: U32\n"),
+            ),
+        ];
+        let matches = identify_account(&account, &candidates);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].name(), "pair");
+    }
+
+    #[test]
+    fn test_program_accounts_retry_exhausted() {
+        let rpc_client = mock_program_rpcclient("fails", json!("unavailable"));
+        assert!(matches!(
+            solana_program_accounts(
+                &rpc_client,
+                &Pubkey::new_unique(),
+                &RetryPolicy::new(3, Duration::ZERO)
+            ),
+            Err(SadAccountErrorType::FailedProgramAccountGet(3))
+        ));
+    }
 }
@@ -33,6 +33,14 @@ pub fn build_command_line_parser() -> App<'static> {
                 .action(clap::ArgAction::SetTrue)
                 .help("Combined with -k, generates list of inactivated feature keys for input to solana-test-validator"),
         )
+        .arg(
+            Arg::new("style")
+                .long("style")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("STATUS=SYMBOL:COLOR")
+                .help("Overrides the symbol and color of active, pending or inactive statuses, e.g. pending=~:blue"),
+        )
 }
 
 #[cfg(test)]
@@ -7,7 +7,7 @@ use std::collections::HashSet;
 // in solana-test-validator all features are enabled
 use clparse::build_command_line_parser;
 use gadgets_scfs::{ScfsCriteria, ScfsMatrix};
-use utils::write_matrix_stdio;
+use utils::{apply_style, terminal_styles, write_matrix_stdio};
 
 mod clparse;
 mod utils;
@@ -47,7 +47,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Error: -k can only be used with -c devnet OR -c testnet OR -c mainnet. Found -c {:?}", intersection);
         }
     } else {
-        write_matrix_stdio(&matrix_result);
+        let mut styles = terminal_styles();
+        for spec in matches.values_of("style").into_iter().flatten() {
+            apply_style(&mut styles, spec)?;
+        }
+        write_matrix_stdio(&matrix_result, &styles);
     }
 
     Ok(())
@@ -1,6 +1,8 @@
 //! @brief solana-features-diff utility functions
 use console::{style, StyledObject};
-use gadgets_scfs::{ScfsMatrix, ScfsRow, ScfsStatus, SCFS_DESCRIPTION, SCFS_FEATURE_ID};
+use gadgets_scfs::{
+    ScfsMatrix, ScfsRow, ScfsStatusStyle, ScfsStatusStyles, SCFS_DESCRIPTION, SCFS_FEATURE_ID,
+};
 
 #[derive(Debug)]
 struct FieldFormatter {
@@ -39,18 +41,66 @@ impl FieldFormatter {
     }
 }
 
+/// Terminal status styles, blank cells colored green (active),
+/// yellow (pending) or red (inactive)
+pub fn terminal_styles() -> ScfsStatusStyles {
+    ScfsStatusStyles {
+        active: ScfsStatusStyle::new(" ", "green"),
+        pending: ScfsStatusStyle::new("  ", "yellow"),
+        inactive: ScfsStatusStyle::new("  ", "red"),
+    }
+}
+
+/// Applies a `<status>=<symbol>:<color>` style override, e.g. `pending=~:blue`,
+/// where color is a terminal color name or 256 color index
+pub fn apply_style(styles: &mut ScfsStatusStyles, spec: &str) -> Result<(), String> {
+    let (status, style) = spec
+        .split_once('=')
+        .ok_or_else(|| format!("Expected <status>=<symbol>:<color>, found {}", spec))?;
+    let (symbol, color) = style
+        .rsplit_once(':')
+        .ok_or_else(|| format!("Expected <symbol>:<color>, found {}", style))?;
+    console_color(color).ok_or_else(|| format!("Unrecognized color {}", color))?;
+    let target = match status {
+        "active" => &mut styles.active,
+        "pending" => &mut styles.pending,
+        "inactive" => &mut styles.inactive,
+        _ => return Err(format!("Unrecognized status {}", status)),
+    };
+    *target = ScfsStatusStyle::new(symbol, color);
+    Ok(())
+}
+
+/// Terminal color of a color name or 256 color index
+fn console_color(color: &str) -> Option<console::Color> {
+    match color {
+        "black" => Some(console::Color::Black),
+        "red" => Some(console::Color::Red),
+        "green" => Some(console::Color::Green),
+        "yellow" => Some(console::Color::Yellow),
+        "blue" => Some(console::Color::Blue),
+        "magenta" => Some(console::Color::Magenta),
+        "cyan" => Some(console::Color::Cyan),
+        "white" => Some(console::Color::White),
+        "grey" | "gray" => Some(console::Color::Color256(8)),
+        _ => color.parse().ok().map(console::Color::Color256),
+    }
+}
+
 #[derive(Debug)]
 struct MatrixStdOut<'a> {
     matrix: &'a ScfsMatrix,
     fmthdr: Vec<FieldFormatter>,
+    styles: &'a ScfsStatusStyles,
 }
 
 impl<'a> MatrixStdOut<'a> {
-    fn new(matrix: &'a ScfsMatrix) -> Self {
+    fn new(matrix: &'a ScfsMatrix, styles: &'a ScfsStatusStyles) -> Self {
         let fmt = FieldFormatter::build_formats(matrix);
         Self {
             matrix,
             fmthdr: fmt,
+            styles,
         }
     }
 }
@@ -58,6 +108,7 @@ impl<'a> MatrixStdOut<'a> {
 fn fill_format_tuple(
     row: &ScfsRow,
     field_fmt: &Vec<FieldFormatter>,
+    styles: &ScfsStatusStyles,
 ) -> (
     String,
     StyledObject<String>,
@@ -73,15 +124,11 @@ fn fill_format_tuple(
     let mut test_state = style(blank.clone()).bg(console::Color::Black);
     let mut main_state = style(blank.clone()).bg(console::Color::Black);
     let mut desc = "".to_string();
-    fn fill_status(status: &ScfsStatus) -> StyledObject<String> {
-        let yes = " ".to_string();
-        let no = "  ".to_string();
-        match status {
-            ScfsStatus::Inactive => style(no).bg(console::Color::Red),
-            ScfsStatus::Pending => style(no).bg(console::Color::Yellow),
-            ScfsStatus::Active(_) => style(yes).bg(console::Color::Green),
-        }
-    }
+    let fill_status = |status| {
+        let status_style = styles.style_for(status);
+        style(status_style.symbol.clone())
+            .bg(console_color(&status_style.color).unwrap_or(console::Color::Black))
+    };
     let row_status = row.status();
     for ff in field_fmt {
         match ff.field_name.as_str() {
@@ -129,7 +176,8 @@ impl std::fmt::Display for MatrixStdOut<'_> {
         )?;
         // Data fields
         for row in self.matrix.get_result_rows() {
-            let (pk, local, dev, test, main, desc) = fill_format_tuple(row, &self.fmthdr);
+            let (pk, local, dev, test, main, desc) =
+                fill_format_tuple(row, &self.fmthdr, self.styles);
             writeln!(
                 f,
                 "{:<44} | {:^8} | {:^8} | {:^8} | {:^8} | {:<95}",
@@ -142,18 +190,55 @@ impl std::fmt::Display for MatrixStdOut<'_> {
     }
 }
 
-pub fn write_matrix_stdio(matrix: &ScfsMatrix) {
-    let mxout = MatrixStdOut::new(matrix);
+pub fn write_matrix_stdio(matrix: &ScfsMatrix, styles: &ScfsStatusStyles) {
+    let mxout = MatrixStdOut::new(matrix, styles);
     println!("{}", mxout);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::write_matrix_stdio;
+    use crate::utils::{apply_style, terminal_styles, write_matrix_stdio, MatrixStdOut};
     use gadgets_scfs::{
-        ScfsCriteria, ScfsMatrix, SCFS_DEVNET, SCFS_LOCAL, SCFS_MAINNET, SCFS_TESTNET,
+        ScfsCriteria, ScfsMatrix, ScfsStatus, ScfsStatusStyle, SCFS_DEVNET, SCFS_FEATURE_PKS,
+        SCFS_LOCAL, SCFS_MAINNET, SCFS_TESTNET,
     };
 
+    #[test]
+    fn test_custom_styles_pass() {
+        let mut styles = terminal_styles();
+        assert!(apply_style(&mut styles, "pending=~:blue").is_ok());
+        assert!(apply_style(&mut styles, "inactive=x:grey").is_ok());
+        assert_eq!(styles.pending, ScfsStatusStyle::new("~", "blue"));
+        assert!(apply_style(&mut styles, "waiting=~:blue").is_err());
+        assert!(apply_style(&mut styles, "pending=~:mauve").is_err());
+        let mut my_matrix = ScfsMatrix::new(Some(ScfsCriteria {
+            features: Some(SCFS_FEATURE_PKS[..2].to_vec()),
+            clusters: Some(vec![SCFS_DEVNET.to_string()]),
+            ..Default::default()
+        }))
+        .unwrap();
+        my_matrix
+            .set_cluster_column(
+                &SCFS_DEVNET,
+                vec![ScfsStatus::Pending, ScfsStatus::Inactive],
+            )
+            .unwrap();
+        let rendered = MatrixStdOut::new(&my_matrix, &styles).to_string();
+        let cells: Vec<&str> = rendered
+            .lines()
+            .skip(2)
+            // The devnet column follows the key and local columns
+            .map(|l| l.split(" | ").nth(2).unwrap().trim())
+            .collect();
+        assert_eq!(
+            cells
+                .iter()
+                .map(|c| console::strip_ansi_codes(c).to_string())
+                .collect::<Vec<String>>(),
+            vec!["~", "x"]
+        );
+    }
+
     #[tokio::test]
     async fn test_local_pass() {
         let mut cluster_vec = Vec::<String>::new();
@@ -164,7 +249,7 @@ mod tests {
         }))
         .unwrap();
         assert!(my_matrix.run().await.is_ok());
-        write_matrix_stdio(&my_matrix);
+        write_matrix_stdio(&my_matrix, &terminal_styles());
     }
     #[tokio::test]
     async fn test_devnet_pass() {
@@ -176,7 +261,7 @@ mod tests {
         }))
         .unwrap();
         assert!(my_matrix.run().await.is_ok());
-        write_matrix_stdio(&my_matrix);
+        write_matrix_stdio(&my_matrix, &terminal_styles());
     }
     #[tokio::test]
     async fn test_testnet_pass() {
@@ -188,7 +273,7 @@ mod tests {
         }))
         .unwrap();
         assert!(my_matrix.run().await.is_ok());
-        write_matrix_stdio(&my_matrix);
+        write_matrix_stdio(&my_matrix, &terminal_styles());
     }
     #[tokio::test]
     async fn test_mainnet_pass() {
@@ -200,6 +285,6 @@ mod tests {
         }))
         .unwrap();
         assert!(my_matrix.run().await.is_ok());
-        write_matrix_stdio(&my_matrix);
+        write_matrix_stdio(&my_matrix, &terminal_styles());
     }
 }